@@ -1,10 +1,15 @@
-//! Utility functions for visuals that the game API exposes on the `console`
-//! object.
+//! Utility functions for logging and visuals that the game API exposes on the
+//! `console` object.
+use std::sync::atomic::{AtomicU8, Ordering};
+
 use js_sys::JsString;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn log_internal(message: &str);
+
     /// Add a visual, in json format, or multiple visuals separated by `\n`.
     /// Each line must be:
     ///   - A serialized [`Visual`], applying to a given room, if the target is
@@ -36,3 +41,94 @@ extern "C" {
     #[wasm_bindgen(js_namespace = console, js_name = clearVisual)]
     pub fn clear_visual(target: Option<&JsString>);
 }
+
+/// The severity of a message logged with [`log_level`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+}
+
+impl LogLevel {
+    const fn from_u8(level: u8) -> LogLevel {
+        match level {
+            0 => LogLevel::Error,
+            1 => LogLevel::Warn,
+            2 => LogLevel::Info,
+            _ => LogLevel::Debug,
+        }
+    }
+
+    const fn tag(self) -> &'static str {
+        match self {
+            LogLevel::Error => "[ERROR]",
+            LogLevel::Warn => "[WARN]",
+            LogLevel::Info => "[INFO]",
+            LogLevel::Debug => "[DEBUG]",
+        }
+    }
+}
+
+static LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Debug as u8);
+
+/// Set the most verbose [`LogLevel`] which [`log_level`] sends to the
+/// console; messages with a more verbose level are discarded. Defaults to
+/// [`LogLevel::Debug`], logging all messages.
+pub fn set_log_level(level: LogLevel) {
+    LOG_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Get the most verbose [`LogLevel`] which [`log_level`] sends to the
+/// console.
+pub fn get_log_level() -> LogLevel {
+    LogLevel::from_u8(LOG_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Log a message to the console, tagged with its severity, if `level` is at
+/// or below the level set with [`set_log_level`].
+///
+/// Messages above the set level are discarded without calling into
+/// JavaScript, so disabled logging costs very little CPU.
+pub fn log_level(level: LogLevel, message: &str) {
+    log_filtered(level, get_log_level(), message, log_internal);
+}
+
+fn log_filtered(level: LogLevel, threshold: LogLevel, message: &str, sink: impl FnOnce(&str)) {
+    if level <= threshold {
+        sink(&format!("{} {}", level.tag(), message));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use super::{get_log_level, log_filtered, set_log_level, LogLevel};
+
+    #[test]
+    fn messages_below_threshold_are_dropped() {
+        let logged = RefCell::new(Vec::new());
+        let sink = |message: &str| logged.borrow_mut().push(message.to_owned());
+
+        log_filtered(LogLevel::Error, LogLevel::Warn, "tower out of energy", sink);
+        log_filtered(LogLevel::Warn, LogLevel::Warn, "spawn queue full", sink);
+        log_filtered(LogLevel::Info, LogLevel::Warn, "creep spawned", sink);
+        log_filtered(LogLevel::Debug, LogLevel::Warn, "path cached", sink);
+
+        assert_eq!(
+            logged.into_inner(),
+            vec!["[ERROR] tower out of energy", "[WARN] spawn queue full"]
+        );
+    }
+
+    #[test]
+    fn log_level_setting() {
+        assert_eq!(get_log_level(), LogLevel::Debug);
+        set_log_level(LogLevel::Warn);
+        assert_eq!(get_log_level(), LogLevel::Warn);
+        set_log_level(LogLevel::Debug);
+    }
+}
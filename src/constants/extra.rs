@@ -1,5 +1,6 @@
 //! Additional constants representing internal game mechanics that aren't
 //! included in the game's constants
+use super::Terrain;
 
 /// Percentage of energy spent on construction that is left in a [`Resource`] if
 /// the construction site is destroyed by [`ConstructionSite::remove`], or from
@@ -163,6 +164,26 @@ pub const MEMORY_SIZE_LIMIT: u32 = 2 * 1024 * 1024;
 /// [`Part::Move`]: crate::constants::Part::Move
 pub const MOVE_POWER: u32 = 2;
 
+/// Fatigue generated per non-[`Part::Move`] body part when a creep moves onto
+/// a tile with the given terrain, or `None` if the tile can't be moved onto.
+///
+/// Roads reduce the cost to 1 on any terrain, including natural walls, which
+/// can be moved through once a road (tunnel) is built on them. Otherwise,
+/// plains cost 2, swamps cost 10, and walls are impassable.
+///
+/// [Code reference](https://github.com/screeps/engine/blob/c6c4fc9e656f160e0e0174b0dd9a817d2dd18976/src/processor/intents/movement.js)
+///
+/// [`Part::Move`]: crate::constants::Part::Move
+#[inline]
+pub const fn movement_cost(terrain: Terrain, on_road: bool) -> Option<u32> {
+    match (terrain, on_road) {
+        (_, true) => Some(1),
+        (Terrain::Plain, false) => Some(2),
+        (Terrain::Swamp, false) => Some(10),
+        (Terrain::Wall, false) => None,
+    }
+}
+
 /// Maximum length (in UTF-16 units) of message content sent to
 /// [`game::notify`].
 ///
@@ -265,3 +286,21 @@ pub const SPAWN_NAME_MAX_LENGTH: u32 = 100;
 ///
 /// [`StructureTerminal`]: crate::objects::StructureTerminal
 pub const TERMINAL_SEND_COST_SCALE: u32 = 30;
+
+#[cfg(test)]
+mod test {
+    use super::movement_cost;
+    use crate::constants::Terrain;
+
+    #[test]
+    fn terrain_movement_costs() {
+        assert_eq!(movement_cost(Terrain::Plain, false), Some(2));
+        assert_eq!(movement_cost(Terrain::Swamp, false), Some(10));
+        assert_eq!(movement_cost(Terrain::Wall, false), None);
+
+        assert_eq!(movement_cost(Terrain::Plain, true), Some(1));
+        assert_eq!(movement_cost(Terrain::Swamp, true), Some(1));
+        // roads over natural walls (tunnels) are passable
+        assert_eq!(movement_cost(Terrain::Wall, true), Some(1));
+    }
+}
@@ -60,9 +60,13 @@ impl FromReturnCode for ErrorCode {
             -12 => Err(ErrorCode::NoBodypart),
             -14 => Err(ErrorCode::RclNotEnough),
             -15 => Err(ErrorCode::GclNotEnough),
-            // SAFETY: Return codes must always be one of the values already covered
             #[cfg(feature = "unsafe-return-conversion")]
-            _ => unsafe { std::hint::unreachable_unchecked() },
+            _ => {
+                #[cfg(feature = "debug-return-conversion")]
+                debug_assert!(false, "unexpected return code: {val}");
+                // SAFETY: Return codes must always be one of the values already covered
+                unsafe { std::hint::unreachable_unchecked() }
+            }
             #[cfg(not(feature = "unsafe-return-conversion"))]
             _ => unreachable!(),
         }
@@ -91,6 +95,9 @@ impl FromReturnCode for ErrorCode {
 }
 
 /// Translates direction constants.
+///
+/// Serializes as the engine's integer constant for the direction, from
+/// `TOP = 1` through `TOP_LEFT = 8`.
 #[wasm_bindgen]
 #[derive(
     Debug,
@@ -506,8 +513,75 @@ impl Density {
 
 /// Translates `ORDER_*` constants.
 #[wasm_bindgen]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Sequence)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence)]
 pub enum OrderType {
     Sell = "sell",
     Buy = "buy",
 }
+
+named_enum_serialize_deserialize!(OrderType);
+
+#[cfg(test)]
+mod test {
+    use super::{Direction, ErrorCode, Part};
+    use crate::traits::FromReturnCode;
+
+    #[test]
+    fn direction_serializes_to_engine_constants() {
+        for (direction, constant) in enum_iterator::all::<Direction>().zip(1..=8) {
+            let serialized = serde_json::to_string(&direction).unwrap();
+            assert_eq!(serialized, constant.to_string());
+            assert_eq!(
+                serde_json::from_str::<Direction>(&serialized).unwrap(),
+                direction
+            );
+        }
+
+        assert!(serde_json::from_str::<Direction>("0").is_err());
+        assert!(serde_json::from_str::<Direction>("9").is_err());
+        assert!(serde_json::from_str::<Direction>("255").is_err());
+    }
+
+    #[test]
+    fn part_costs_and_strings() {
+        let parts = [
+            (Part::Move, 50, "move"),
+            (Part::Work, 100, "work"),
+            (Part::Carry, 50, "carry"),
+            (Part::Attack, 80, "attack"),
+            (Part::RangedAttack, 150, "ranged_attack"),
+            (Part::Tough, 10, "tough"),
+            (Part::Heal, 250, "heal"),
+            (Part::Claim, 600, "claim"),
+        ];
+        for (part, cost, name) in parts {
+            assert_eq!(part.cost(), cost);
+            assert_eq!(part.to_str(), name);
+            assert_eq!(part.to_string(), name);
+            assert_eq!(name.parse::<Part>().unwrap(), part);
+        }
+
+        assert!("MOVE".parse::<Part>().is_err());
+        assert!("rangedAttack".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn valid_return_codes() {
+        assert_eq!(ErrorCode::result_from_i8(0), Ok(()));
+        assert_eq!(ErrorCode::result_from_i8(-9), Err(ErrorCode::NotInRange));
+        assert_eq!(ErrorCode::result_from_i8(-15), Err(ErrorCode::GclNotEnough));
+    }
+
+    // without the unsafe conversion, the code is always checked; with it, the
+    // check is only done for debug-return-conversion with debug assertions on,
+    // and an out-of-range code is undefined behavior otherwise
+    #[cfg(any(
+        not(feature = "unsafe-return-conversion"),
+        all(feature = "debug-return-conversion", debug_assertions)
+    ))]
+    #[test]
+    #[should_panic]
+    fn out_of_range_return_code() {
+        let _ = ErrorCode::result_from_i8(-13);
+    }
+}
@@ -483,6 +483,83 @@ impl ResourceType {
         };
         Some(boost)
     }
+
+    /// Whether this is a raw mineral, which can be harvested from a
+    /// [`Mineral`].
+    ///
+    /// [`Mineral`]: crate::objects::Mineral
+    #[inline]
+    pub const fn is_mineral(self) -> bool {
+        use ResourceType::*;
+        match self {
+            Hydrogen | Oxygen | Utrium | Lemergium | Keanium | Zynthium | Catalyst => true,
+            #[cfg(feature = "seasonal-season-5")]
+            Thorium => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this is a compound which can be used to boost a creep; see
+    /// [`ResourceType::boost`] for its effect.
+    #[inline]
+    pub const fn is_boost(self) -> bool {
+        self.boost().is_some()
+    }
+
+    /// The tier of this boost compound, from 1 for the base compounds like
+    /// `UH` up to 3 for catalyzed compounds like `XUH2O`, or `None` if this
+    /// isn't a boost.
+    #[inline]
+    pub const fn boost_tier(self) -> Option<u8> {
+        use ResourceType::*;
+        let tier = match self {
+            UtriumHydride | UtriumOxide | KeaniumHydride | KeaniumOxide | LemergiumHydride
+            | LemergiumOxide | ZynthiumHydride | ZynthiumOxide | GhodiumHydride | GhodiumOxide => 1,
+            UtriumAcid | UtriumAlkalide | KeaniumAcid | KeaniumAlkalide | LemergiumAcid
+            | LemergiumAlkalide | ZynthiumAcid | ZynthiumAlkalide | GhodiumAcid
+            | GhodiumAlkalide => 2,
+            CatalyzedUtriumAcid
+            | CatalyzedUtriumAlkalide
+            | CatalyzedKeaniumAcid
+            | CatalyzedKeaniumAlkalide
+            | CatalyzedLemergiumAcid
+            | CatalyzedLemergiumAlkalide
+            | CatalyzedZynthiumAcid
+            | CatalyzedZynthiumAlkalide
+            | CatalyzedGhodiumAcid
+            | CatalyzedGhodiumAlkalide => 3,
+            _ => return None,
+        };
+        Some(tier)
+    }
+
+    /// Whether this is a commodity: a resource harvested from a [`Deposit`],
+    /// or one produced from minerals, energy, or other commodities in a
+    /// factory.
+    ///
+    /// [`Deposit`]: crate::objects::Deposit
+    #[inline]
+    pub const fn is_commodity(self) -> bool {
+        use ResourceType::*;
+        matches!(
+            self,
+            // deposit resources
+            Silicon | Metal | Biomass | Mist
+                // compressed commodities
+                | UtriumBar | LemergiumBar | ZynthiumBar | KeaniumBar | GhodiumMelt | Oxidant
+                | Reductant | Purifier | Battery
+                // basic regional commodities
+                | Composite | Crystal | Liquid
+                // electronics chain
+                | Wire | Switch | Transistor | Microchip | Circuit | Device
+                // biological chain
+                | Cell | Phlegm | Tissue | Muscle | Organoid | Organism
+                // mechanical chain
+                | Alloy | Tube | Fixtures | Frame | Hydraulics | Machine
+                // mystical chain
+                | Condensate | Concentrate | Extract | Spirit | Emanation | Essence
+        )
+    }
 }
 
 /// Returned values from [`ResourceType::boost`] representing the effect of
@@ -522,7 +599,7 @@ impl wasm_bindgen::convert::FromWasmAbi for MarketResourceType {
                 // try with IntershardResourceType
                 match IntershardResourceType::from_js_value(&s) {
                     Some(r) => Self::IntershardResource(r),
-                    None => Self::Resource(ResourceType::__Nonexhaustive),
+                    None => Self::Resource(ResourceType::__Invalid),
                 }
             }
         }
@@ -682,7 +759,7 @@ mod test {
     #[test]
     fn resources_rust_to_serde_json_from_serde_json_roundtrip() {
         for resource in enum_iterator::all::<ResourceType>() {
-            if resource != ResourceType::__Nonexhaustive {
+            if resource != ResourceType::__Invalid {
                 let serialized = serde_json::to_string(&resource).unwrap();
                 let parsed: ResourceType = serde_json::from_str(&serialized).unwrap();
                 assert_eq!(resource, parsed);
@@ -693,7 +770,7 @@ mod test {
     #[test]
     fn resources_rust_to_display_from_str_roundtrip() {
         for resource in enum_iterator::all::<ResourceType>() {
-            if resource != ResourceType::__Nonexhaustive {
+            if resource != ResourceType::__Invalid {
                 let string = format!("{}", resource);
                 let parsed = ResourceType::from_str(&string).unwrap();
                 assert_eq!(resource, parsed);
@@ -705,7 +782,7 @@ mod test {
     fn resources_rust_vec_to_serde_json_from_serde_json_roundtrip() {
         let mut resources = vec![];
         for resource in enum_iterator::all::<ResourceType>() {
-            if resource != ResourceType::__Nonexhaustive {
+            if resource != ResourceType::__Invalid {
                 resources.push(resource);
             }
         }
@@ -718,7 +795,7 @@ mod test {
     fn resources_rust_vec_to_serde_json_from_serde_json_roundtrip_via_values() {
         let mut resources = vec![];
         for resource in enum_iterator::all::<ResourceType>() {
-            if resource != ResourceType::__Nonexhaustive {
+            if resource != ResourceType::__Invalid {
                 resources.push(resource);
             }
         }
@@ -735,7 +812,7 @@ mod test {
     #[test]
     fn intershard_resources_rust_to_serde_json_from_serde_json_roundtrip() {
         for resource in enum_iterator::all::<IntershardResourceType>() {
-            if resource != IntershardResourceType::__Nonexhaustive {
+            if resource != IntershardResourceType::__Invalid {
                 let serialized = serde_json::to_string(&resource).unwrap();
                 let parsed: IntershardResourceType = serde_json::from_str(&serialized).unwrap();
                 assert_eq!(resource, parsed);
@@ -746,7 +823,7 @@ mod test {
     #[test]
     fn intershard_resources_rust_to_display_from_str_roundtrip() {
         for resource in enum_iterator::all::<IntershardResourceType>() {
-            if resource != IntershardResourceType::__Nonexhaustive {
+            if resource != IntershardResourceType::__Invalid {
                 let string = format!("{}", resource);
                 let parsed = IntershardResourceType::from_str(&string).unwrap();
                 assert_eq!(resource, parsed);
@@ -758,7 +835,7 @@ mod test {
     fn intershard_resources_rust_vec_to_serde_json_from_serde_json_roundtrip() {
         let mut resources = vec![];
         for resource in enum_iterator::all::<IntershardResourceType>() {
-            if resource != IntershardResourceType::__Nonexhaustive {
+            if resource != IntershardResourceType::__Invalid {
                 resources.push(resource);
             }
         }
@@ -772,7 +849,7 @@ mod test {
     fn intershard_resources_rust_vec_to_serde_json_from_serde_json_roundtrip_via_values() {
         let mut resources = vec![];
         for resource in enum_iterator::all::<IntershardResourceType>() {
-            if resource != IntershardResourceType::__Nonexhaustive {
+            if resource != IntershardResourceType::__Invalid {
                 resources.push(resource);
             }
         }
@@ -789,10 +866,10 @@ mod test {
     #[test]
     fn market_resources_rust_to_serde_json_from_serde_json_roundtrip() {
         for resource in enum_iterator::all::<MarketResourceType>() {
-            if resource != MarketResourceType::Resource(ResourceType::__Nonexhaustive)
+            if resource != MarketResourceType::Resource(ResourceType::__Invalid)
                 && resource
                     != MarketResourceType::IntershardResource(
-                        IntershardResourceType::__Nonexhaustive,
+                        IntershardResourceType::__Invalid,
                     )
             {
                 let serialized = serde_json::to_string(&resource).unwrap();
@@ -806,10 +883,10 @@ mod test {
     fn market_resources_rust_vec_to_serde_json_from_serde_json_roundtrip() {
         let mut resources = vec![];
         for resource in enum_iterator::all::<MarketResourceType>() {
-            if resource != MarketResourceType::Resource(ResourceType::__Nonexhaustive)
+            if resource != MarketResourceType::Resource(ResourceType::__Invalid)
                 && resource
                     != MarketResourceType::IntershardResource(
-                        IntershardResourceType::__Nonexhaustive,
+                        IntershardResourceType::__Invalid,
                     )
             {
                 resources.push(resource);
@@ -825,10 +902,10 @@ mod test {
     fn market_resources_rust_vec_to_serde_json_from_serde_json_roundtrip_via_values() {
         let mut resources = vec![];
         for resource in enum_iterator::all::<MarketResourceType>() {
-            if resource != MarketResourceType::Resource(ResourceType::__Nonexhaustive)
+            if resource != MarketResourceType::Resource(ResourceType::__Invalid)
                 && resource
                     != MarketResourceType::IntershardResource(
-                        IntershardResourceType::__Nonexhaustive,
+                        IntershardResourceType::__Invalid,
                     )
             {
                 resources.push(resource);
@@ -843,4 +920,35 @@ mod test {
             .collect();
         assert_eq!(resources, resources_reparsed_native);
     }
+
+    #[test]
+    fn resource_classification() {
+        use ResourceType::*;
+
+        let classify = |resource: ResourceType| {
+            (
+                resource.is_mineral(),
+                resource.is_boost(),
+                resource.boost_tier(),
+                resource.is_commodity(),
+            )
+        };
+
+        assert_eq!(classify(Energy), (false, false, None, false));
+        assert_eq!(classify(Utrium), (true, false, None, false));
+        assert_eq!(classify(UtriumHydride), (false, true, Some(1), false));
+        assert_eq!(classify(UtriumAcid), (false, true, Some(2), false));
+        assert_eq!(classify(CatalyzedUtriumAcid), (false, true, Some(3), false));
+        assert_eq!(classify(UtriumBar), (false, false, None, true));
+        assert_eq!(classify(Silicon), (false, false, None, true));
+
+        // intermediate compounds that aren't boosts themselves
+        assert_eq!(classify(Hydroxide), (false, false, None, false));
+        assert_eq!(classify(ZynthiumKeanite), (false, false, None, false));
+        assert_eq!(classify(Ghodium), (false, false, None, false));
+
+        for resource in enum_iterator::all::<ResourceType>() {
+            assert_eq!(resource.is_boost(), resource.boost_tier().is_some());
+        }
+    }
 }
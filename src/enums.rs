@@ -257,7 +257,7 @@ pub enum TypedRoomObject {
     Tombstone,
 }
 
-#[enum_dispatch(SharedCreepProperties)]
+#[enum_dispatch(SharedCreepProperties, NotifyWhenAttacked)]
 pub enum MovableObject {
     Creep,
     PowerCreep,
@@ -265,7 +265,7 @@ pub enum MovableObject {
 
 /// Enum used for converting a [`Structure`] into a typed object of its specific
 /// structure type.
-#[enum_dispatch(StructureProperties, HasPosition)]
+#[enum_dispatch(StructureProperties, HasPosition, NotifyWhenAttacked)]
 #[derive(Clone, Debug)]
 pub enum StructureObject {
     StructureContainer,
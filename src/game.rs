@@ -10,16 +10,19 @@
 //! the behavior of stale game objects is undefined.
 //!
 //! [Screeps documentation](http://docs.screeps.com/api/#Game)
+use std::collections::HashMap;
+
 use js_sys::{JsString, Object};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::IntershardResourceType,
+    constants::{IntershardResourceType, ResourceType, MAX_CONSTRUCTION_SITES},
     enums::StructureObject,
     js_collections::{JsHashMap, JsObjectId},
     local::{ObjectId, RawObjectId, RoomName},
     objects::{AccountPowerCreep, ConstructionSite, Creep, Flag, Room, RoomObject, StructureSpawn},
-    traits::MaybeHasId,
+    traits::{MaybeHasId, OwnedStructureProperties},
 };
 
 pub mod cpu;
@@ -83,6 +86,22 @@ pub fn construction_sites() -> JsHashMap<RawObjectId, ConstructionSite> {
     Game::construction_sites().into()
 }
 
+/// Get all of your construction sites as a [`Vec`], read from
+/// `Game.constructionSites` in one pass.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.constructionSites)
+pub fn construction_sites_vec() -> Vec<ConstructionSite> {
+    construction_sites().values().collect()
+}
+
+/// Whether you have fewer than [`MAX_CONSTRUCTION_SITES`] construction sites,
+/// so that placing another won't fail for being over the limit.
+///
+/// [`MAX_CONSTRUCTION_SITES`]: crate::constants::MAX_CONSTRUCTION_SITES
+pub fn can_place_construction_site() -> bool {
+    construction_sites().len() < MAX_CONSTRUCTION_SITES as usize
+}
+
 /// Get a [`JsHashMap<String, Creep>`] with all of your creeps, which has creep
 /// names as keys.
 ///
@@ -105,6 +124,25 @@ pub fn creeps_jsstring() -> JsHashMap<JsString, Creep> {
     Game::creeps().into()
 }
 
+/// Get all of your creeps with the given `role` field in their memory.
+///
+/// Creeps whose memory has no `role`, or a `role` which isn't a string, are
+/// never included.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.creeps)
+pub fn creeps_with_role(role: &str) -> Vec<Creep> {
+    let creeps = creeps().values().map(|creep| (creep.memory_role(), creep));
+    with_role(creeps, role)
+}
+
+/// Keeps the items whose role matches.
+fn with_role<T>(items: impl Iterator<Item = (Option<String>, T)>, role: &str) -> Vec<T> {
+    items
+        .filter(|(item_role, _)| item_role.as_deref() == Some(role))
+        .map(|(_, item)| item)
+        .collect()
+}
+
 /// Get a [`JsHashMap<String, Flag>`] with all of your flags, which has flag
 /// names as keys.
 ///
@@ -113,6 +151,18 @@ pub fn flags() -> JsHashMap<String, Flag> {
     Game::flags().into()
 }
 
+/// Get a [`HashMap<String, Flag>`] with all of your flags, which has flag names
+/// as keys, read from `Game.flags` in one pass.
+///
+/// Each name is copied out of JavaScript into a [`String`] key once, so later
+/// lookups by name don't need to call into JavaScript. Flag names can hold any
+/// Unicode text, and are kept in full.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.flags)
+pub fn flags_by_name() -> HashMap<String, Flag> {
+    flags().entries().collect()
+}
+
 /// Get a [`JsHashMap<JsString, Flag>`] with all of your flags, which has flag
 /// names as keys.
 ///
@@ -153,6 +203,88 @@ pub fn rooms() -> JsHashMap<RoomName, Room> {
     Game::rooms().into()
 }
 
+/// Get the rooms visible for the current tick, each paired with its name, as a
+/// [`Vec`] which can be sorted and filtered in Rust.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.rooms)
+pub fn rooms_vec() -> Vec<(RoomName, Room)> {
+    rooms().entries().collect()
+}
+
+/// The energy levels of one of your rooms, as collected by
+/// [`owned_rooms_energy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RoomEnergySnapshot {
+    /// Energy in the room's spawns and extensions, available for spawning.
+    pub energy_available: u32,
+    /// Total energy capacity of the room's spawns and extensions.
+    pub energy_capacity_available: u32,
+    /// Energy in the room's storage, `None` if the room has no storage.
+    pub storage_energy: Option<u32>,
+}
+
+impl RoomEnergySnapshot {
+    /// Read the current energy levels of a room.
+    pub fn from_room(room: &Room) -> Self {
+        RoomEnergySnapshot {
+            energy_available: room.energy_available(),
+            energy_capacity_available: room.energy_capacity_available(),
+            storage_energy: room.storage().and_then(|storage| {
+                storage
+                    .store()
+                    .get_used_capacity(Some(ResourceType::Energy))
+            }),
+        }
+    }
+}
+
+/// Get a [`RoomEnergySnapshot`] for each room whose controller you own,
+/// keyed by room name.
+pub fn owned_rooms_energy() -> HashMap<RoomName, RoomEnergySnapshot> {
+    owned_energy_snapshots(rooms().values().map(|room| {
+        let my = room.controller().is_some_and(|controller| controller.my());
+        (room.name(), my, RoomEnergySnapshot::from_room(&room))
+    }))
+}
+
+/// Keeps the snapshots of the rooms marked as yours, given each room's name,
+/// whether you own its controller, and its snapshot.
+fn owned_energy_snapshots(
+    rooms: impl Iterator<Item = (RoomName, bool, RoomEnergySnapshot)>,
+) -> HashMap<RoomName, RoomEnergySnapshot> {
+    rooms
+        .filter(|&(_, my, _)| my)
+        .map(|(name, _, snapshot)| (name, snapshot))
+        .collect()
+}
+
+/// Get the total amount of each resource held in the storages and terminals
+/// of all rooms whose controller you own.
+///
+/// Resources which none of these hold are left out.
+pub fn empire_inventory() -> HashMap<ResourceType, u32> {
+    let stores = rooms()
+        .values()
+        .filter(|room| room.controller().is_some_and(|controller| controller.my()))
+        .flat_map(|room| {
+            let storage = room.storage().map(|storage| storage.store());
+            let terminal = room.terminal().map(|terminal| terminal.store());
+            storage.into_iter().chain(terminal)
+        });
+
+    sum_resources(stores.flat_map(|store| store.iter()))
+}
+
+/// Totals resource amounts, saturating at `u32::MAX`.
+fn sum_resources(amounts: impl Iterator<Item = (ResourceType, u32)>) -> HashMap<ResourceType, u32> {
+    let mut totals = HashMap::new();
+    for (ty, amount) in amounts {
+        let total: &mut u32 = totals.entry(ty).or_default();
+        *total = total.saturating_add(amount);
+    }
+    totals
+}
+
 /// Get a [`JsHashMap<String, StructureSpawn>`] with all of your spawns, which
 /// has spawn names as keys.
 ///
@@ -237,6 +369,25 @@ pub fn get_object_by_id_erased(id: &RawObjectId) -> Option<RoomObject> {
     Game::get_object_by_id(&js_str)
 }
 
+/// Get the object represented by a given ID string if it's still alive and
+/// visible, and is an instance of `T`.
+///
+/// Unlike [`get_object_by_js_id_typed`] and [`get_object_by_id_typed`], which
+/// trust the type of the ID, this checks the type of the object the game
+/// returns and gives `None` if it's any other type, such as a
+/// [`StructureSpawn`] when asking for a [`StructureContainer`].
+///
+/// [Screeps documentation](http://docs.screeps.com/api/#Game.getObjectById)
+///
+/// [`StructureSpawn`]: crate::objects::StructureSpawn
+/// [`StructureContainer`]: crate::objects::StructureContainer
+pub fn get_typed_object_by_id<T>(id: &JsString) -> Option<T>
+where
+    T: MaybeHasId + JsCast,
+{
+    Game::get_object_by_id(id).and_then(|object| object.dyn_into().ok())
+}
+
 /// Send an email message to yourself with a given message.
 ///
 /// Set a `group_interval` with a limit, in minutes, on how frequently emails
@@ -251,3 +402,127 @@ pub fn notify(message: &str, group_interval: Option<u32>) {
 
     Game::notify(&message, group_interval)
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{owned_energy_snapshots, sum_resources, with_role, RoomEnergySnapshot};
+    use crate::{constants::ResourceType, local::RoomName};
+
+    #[test]
+    fn inventory_across_rooms() {
+        // storage and terminal contents of two rooms, the second of which
+        // has no terminal
+        let first_storage = vec![
+            (ResourceType::Energy, 400_000),
+            (ResourceType::Utrium, 12_000),
+            (ResourceType::Battery, 500),
+        ];
+        let first_terminal = vec![
+            (ResourceType::Energy, 50_000),
+            (ResourceType::Oxygen, 3_000),
+        ];
+        let second_storage = vec![
+            (ResourceType::Energy, 150_000),
+            (ResourceType::Utrium, 8_000),
+        ];
+
+        let stores = [first_storage, first_terminal, second_storage];
+        assert_eq!(
+            sum_resources(stores.into_iter().flatten()),
+            HashMap::from([
+                (ResourceType::Energy, 600_000),
+                (ResourceType::Utrium, 20_000),
+                (ResourceType::Oxygen, 3_000),
+                (ResourceType::Battery, 500),
+            ])
+        );
+
+        let overflowing = [(ResourceType::Energy, u32::MAX), (ResourceType::Energy, 1)];
+        assert_eq!(
+            sum_resources(overflowing.into_iter()),
+            HashMap::from([(ResourceType::Energy, u32::MAX)])
+        );
+    }
+
+    #[test]
+    fn creeps_filtered_by_role() {
+        // stand-ins for creeps, paired with the `role` in their memory
+        let creeps = || {
+            vec![
+                (Some("harvester".to_owned()), "Harvester1"),
+                (Some("upgrader".to_owned()), "Upgrader1"),
+                (None, "Scout1"),
+                (Some("harvester".to_owned()), "Harvester2"),
+                (Some("Harvester".to_owned()), "Harvester3"),
+            ]
+            .into_iter()
+        };
+
+        assert_eq!(
+            with_role(creeps(), "harvester"),
+            vec!["Harvester1", "Harvester2"]
+        );
+        assert_eq!(with_role(creeps(), "upgrader"), vec!["Upgrader1"]);
+        assert!(with_role(creeps(), "builder").is_empty());
+        assert!(with_role(creeps(), "").is_empty());
+    }
+
+    #[test]
+    fn energy_snapshots_of_owned_rooms() {
+        let w1n1: RoomName = "W1N1".parse().unwrap();
+        let e3s7: RoomName = "E3S7".parse().unwrap();
+        let w2n1: RoomName = "W2N1".parse().unwrap();
+
+        let full = RoomEnergySnapshot {
+            energy_available: 5_600,
+            energy_capacity_available: 5_600,
+            storage_energy: Some(412_000),
+        };
+        let without_storage = RoomEnergySnapshot {
+            energy_available: 300,
+            energy_capacity_available: 550,
+            storage_energy: None,
+        };
+
+        // two owned rooms, one without a storage, and a reserved room
+        let rooms = [
+            (w1n1, true, full),
+            (w2n1, false, RoomEnergySnapshot::default()),
+            (e3s7, true, without_storage),
+        ];
+
+        assert_eq!(
+            owned_energy_snapshots(rooms.into_iter()),
+            HashMap::from([(w1n1, full), (e3s7, without_storage)])
+        );
+    }
+
+    #[test]
+    fn energy_snapshots_round_trip() {
+        let snapshots: HashMap<RoomName, RoomEnergySnapshot> = HashMap::from([
+            (
+                "W1N1".parse().unwrap(),
+                RoomEnergySnapshot {
+                    energy_available: 5_600,
+                    energy_capacity_available: 5_600,
+                    storage_energy: Some(412_000),
+                },
+            ),
+            (
+                "E3S7".parse().unwrap(),
+                RoomEnergySnapshot {
+                    energy_available: 300,
+                    energy_capacity_available: 550,
+                    storage_energy: None,
+                },
+            ),
+        ]);
+
+        let serialized = serde_json::to_string(&snapshots).unwrap();
+        let parsed: HashMap<RoomName, RoomEnergySnapshot> =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(parsed, snapshots);
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! [Screeps documentation](http://docs.screeps.com/api/#Game.cpu)
 use js_sys::{JsString, Object};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 use crate::{constants::ErrorCode, prelude::*};
@@ -30,7 +31,7 @@ extern "C" {
     fn unlocked_time() -> Option<u64>;
 
     #[wasm_bindgen(js_namespace = ["Game"], js_class = "cpu", static_method_of = Cpu, js_name = getHeapStatistics)]
-    fn get_heap_statistics() -> HeapStatistics;
+    fn get_heap_statistics() -> JsValue;
 
     #[wasm_bindgen(js_namespace = ["Game"], js_class = "cpu", static_method_of = Cpu, js_name = getUsed)]
     fn get_used() -> f64;
@@ -90,7 +91,8 @@ pub fn unlocked_time() -> Option<u64> {
 ///
 /// [Screeps documentation](https://docs.screeps.com/api/#Game.cpu.getHeapStatistics)
 pub fn get_heap_statistics() -> HeapStatistics {
-    Cpu::get_heap_statistics()
+    serde_wasm_bindgen::from_value(Cpu::get_heap_statistics())
+        .expect("expected heap statistics object")
 }
 
 /// Get the amount of CPU time used for execution so far this tick.
@@ -100,6 +102,64 @@ pub fn get_used() -> f64 {
     Cpu::get_used()
 }
 
+/// Measures the CPU time used between its creation and a call to
+/// [`CpuTimer::stop`], for profiling sections of code.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::game::cpu::CpuTimer;
+///
+/// let timer = CpuTimer::start();
+/// // run the section of code to measure
+/// let used = timer.stop();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CpuTimer {
+    start: f64,
+    #[cfg(test)]
+    clock: fn() -> f64,
+}
+
+impl CpuTimer {
+    /// Start a timer, reading the CPU used so far with [`get_used`].
+    pub fn start() -> Self {
+        CpuTimer {
+            start: get_used(),
+            #[cfg(test)]
+            clock: get_used,
+        }
+    }
+
+    #[cfg(test)]
+    fn start_with_clock(clock: fn() -> f64) -> Self {
+        CpuTimer {
+            start: clock(),
+            clock,
+        }
+    }
+
+    /// Get the CPU time used since the timer was started, leaving it running.
+    pub fn elapsed(&self) -> f64 {
+        self.now() - self.start
+    }
+
+    /// Stop the timer, returning the CPU time used since it was started.
+    pub fn stop(self) -> f64 {
+        self.elapsed()
+    }
+
+    #[cfg(not(test))]
+    fn now(&self) -> f64 {
+        get_used()
+    }
+
+    #[cfg(test)]
+    fn now(&self) -> f64 {
+        (self.clock)()
+    }
+}
+
 /// Stop execution of your script immediately and requests the destruction of
 /// your code's environment, which will start fresh on the following tick.
 ///
@@ -146,53 +206,97 @@ pub fn generate_pixel() -> Result<(), ErrorCode> {
     ErrorCode::result_from_i8(Cpu::generate_pixel())
 }
 
-#[wasm_bindgen]
-extern "C" {
-    /// Object with info about the memory heap of your virtual machine.
-    ///
-    /// [Screeps documentation](https://docs.screeps.com/api/#Game.cpu.getHeapStatistics)
-    #[wasm_bindgen]
-    pub type HeapStatistics;
-
+/// Information about the memory heap of your virtual machine.
+///
+/// Field names match the keys of the object returned by V8, which are in
+/// `snake_case` rather than the `camelCase` used by the rest of the game API.
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.cpu.getHeapStatistics)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HeapStatistics {
     /// The total heap consumed.
-    #[wasm_bindgen(method, getter)]
-    pub fn total_heap_size(this: &HeapStatistics) -> u32;
-
+    pub total_heap_size: u64,
     /// The total heap consumed by executable code.
-    #[wasm_bindgen(method, getter)]
-    pub fn total_heap_size_executable(this: &HeapStatistics) -> u32;
-
+    pub total_heap_size_executable: u64,
     /// The total amount of heap committed to memory.
-    #[wasm_bindgen(method, getter)]
-    pub fn total_physical_size(this: &HeapStatistics) -> u32;
-
+    pub total_physical_size: u64,
     /// Amount of heap available for allocation.
-    #[wasm_bindgen(method, getter)]
-    pub fn total_available_size(this: &HeapStatistics) -> u32;
-
+    pub total_available_size: u64,
     /// Total heap consumed by application data.
-    #[wasm_bindgen(method, getter)]
-    pub fn used_heap_size(this: &HeapStatistics) -> u32;
-
+    pub used_heap_size: u64,
     /// The allowed limit for total heap memory.
-    #[wasm_bindgen(method, getter)]
-    pub fn heap_size_limit(this: &HeapStatistics) -> u32;
-
+    pub heap_size_limit: u64,
     /// Total amount of memory obtained by malloc.
-    #[wasm_bindgen(method, getter)]
-    pub fn malloced_memory(this: &HeapStatistics) -> u32;
-
+    pub malloced_memory: u64,
     /// Maximum amount of memory obtained by malloc.
-    #[wasm_bindgen(method, getter)]
-    pub fn peak_malloced_memory(this: &HeapStatistics) -> u32;
-
+    pub peak_malloced_memory: u64,
     /// Whether the virtual machine overwrites memory as it deallocates -
     /// usually 0.
-    #[wasm_bindgen(method, getter)]
-    pub fn does_zap_garbage(this: &HeapStatistics) -> u32;
-
+    pub does_zap_garbage: u64,
     /// External allocations that are outside of the v8 heap but still count
     /// against the memory limit.
-    #[wasm_bindgen(method, getter)]
-    pub fn externally_allocated_size(this: &HeapStatistics) -> u32;
+    pub externally_allocated_size: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use super::{CpuTimer, HeapStatistics};
+
+    thread_local! {
+        static MOCK_CPU_USED: Cell<f64> = const { Cell::new(0.) };
+    }
+
+    fn mock_get_used() -> f64 {
+        MOCK_CPU_USED.with(Cell::get)
+    }
+
+    #[test]
+    fn cpu_timer_delta() {
+        MOCK_CPU_USED.with(|used| used.set(1.25));
+        let timer = CpuTimer::start_with_clock(mock_get_used);
+
+        MOCK_CPU_USED.with(|used| used.set(2.0));
+        assert_eq!(timer.elapsed(), 0.75);
+
+        MOCK_CPU_USED.with(|used| used.set(4.5));
+        assert_eq!(timer.stop(), 3.25);
+    }
+
+    #[test]
+    fn heap_statistics_from_engine_object() {
+        // shape of the object returned by `Game.cpu.getHeapStatistics()`,
+        // including a key not represented in `HeapStatistics`
+        let js_object = serde_json::json!({
+            "total_heap_size": 47_497_216,
+            "total_heap_size_executable": 3_670_016,
+            "total_physical_size": 44_197_408,
+            "total_available_size": 260_818_312,
+            "used_heap_size": 39_547_880,
+            "heap_size_limit": 301_989_888,
+            "malloced_memory": 8_192,
+            "peak_malloced_memory": 12_378_504,
+            "does_zap_garbage": 0,
+            "number_of_native_contexts": 1,
+            "externally_allocated_size": 38_045_048,
+        });
+
+        let stats: HeapStatistics = serde_json::from_value(js_object).unwrap();
+        assert_eq!(
+            stats,
+            HeapStatistics {
+                total_heap_size: 47_497_216,
+                total_heap_size_executable: 3_670_016,
+                total_physical_size: 44_197_408,
+                total_available_size: 260_818_312,
+                used_heap_size: 39_547_880,
+                heap_size_limit: 301_989_888,
+                malloced_memory: 8_192,
+                peak_malloced_memory: 12_378_504,
+                does_zap_garbage: 0,
+                externally_allocated_size: 38_045_048,
+            }
+        );
+    }
 }
@@ -1,7 +1,7 @@
 //! Game map related functionality.
 //!
 //! [Screeps documentation](https://docs.screeps.com/api/#Game-map)
-use std::convert::TryInto;
+use std::{collections::HashMap, convert::TryInto};
 
 use enum_iterator::Sequence;
 use js_sys::{Array, JsString, Object};
@@ -54,6 +54,29 @@ pub fn describe_exits(room_name: RoomName) -> JsHashMap<Direction, RoomName> {
     Map::describe_exits(&room_name).into()
 }
 
+/// Get the adjacency graph of a set of rooms, mapping each room to the rooms
+/// in the set that its exits lead to, as given by [`describe_exits`].
+///
+/// Exits to rooms outside of the set are left out, so the graph only covers
+/// travel within the region.
+pub fn room_graph(rooms: &[RoomName]) -> HashMap<RoomName, Vec<RoomName>> {
+    build_room_graph(rooms, |room| describe_exits(room).values().collect())
+}
+
+fn build_room_graph(
+    rooms: &[RoomName],
+    mut exits: impl FnMut(RoomName) -> Vec<RoomName>,
+) -> HashMap<RoomName, Vec<RoomName>> {
+    rooms
+        .iter()
+        .map(|&room| {
+            let mut neighbors = exits(room);
+            neighbors.retain(|neighbor| rooms.contains(neighbor));
+            (room, neighbors)
+        })
+        .collect()
+}
+
 /// Get the distance used for range calculations between two rooms,
 /// optionally setting `continuous` to true to consider the world borders to
 /// wrap around, which is used for terminal calculations.
@@ -341,3 +364,35 @@ where
         Err(unsafe { ErrorCode::result_from_i8(result).unwrap_err_unchecked() })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::build_room_graph;
+    use crate::local::RoomName;
+
+    #[test]
+    fn room_graph_within_region() {
+        let room = |name: &str| name.parse::<RoomName>().unwrap();
+        let (w1n1, w1n2, w2n1, w2n2) = (room("W1N1"), room("W1N2"), room("W2N1"), room("W2N2"));
+
+        // W1N1 and W2N2 each connect to both other rooms in the square, while
+        // the wall between W1N2 and W2N2 blocks that edge; every room also
+        // has exits leading out of the region
+        let exits = |name: RoomName| match name.to_string().as_str() {
+            "W1N1" => vec![w1n2, room("W0N1"), room("W1N0"), w2n1],
+            "W1N2" => vec![room("W1N3"), room("W0N2"), w1n1],
+            "W2N1" => vec![w2n2, w1n1, room("W2N0"), room("W3N1")],
+            "W2N2" => vec![room("W2N3"), w2n1, room("W3N2")],
+            _ => unreachable!(),
+        };
+
+        let graph = build_room_graph(&[w1n1, w1n2, w2n1, w2n2], exits);
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph[&w1n1], vec![w1n2, w2n1]);
+        assert_eq!(graph[&w1n2], vec![w1n1]);
+        assert_eq!(graph[&w2n1], vec![w2n2, w1n1]);
+        assert_eq!(graph[&w2n2], vec![w2n1]);
+
+        assert!(build_room_graph(&[], exits).is_empty());
+    }
+}
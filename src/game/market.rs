@@ -2,6 +2,7 @@
 //!
 //! [Screeps documentation](https://docs.screeps.com/api/#Game-market)
 use js_sys::{Array, JsString, Object};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::{prelude::*, JsCast};
 
 use crate::{
@@ -52,7 +53,7 @@ extern "C" {
     fn get_history(resource: Option<ResourceType>) -> Option<Array>;
 
     #[wasm_bindgen(js_namespace = ["Game"], js_class = "market", static_method_of = Market, js_name = getOrderById)]
-    fn get_order_by_id(order_id: &JsString) -> Option<Order>;
+    fn get_order_by_id(order_id: &JsString) -> JsValue;
 }
 
 /// Your current credit balance.
@@ -165,16 +166,35 @@ pub fn extend_order(order_id: &JsString, add_amount: u32) -> Result<(), ErrorCod
 /// filter. Note that a `resourceType` filter has special handling in the engine
 /// to be more efficient ([source]).
 ///
+/// Orders which can't be read into an [`Order`], such as those for a
+/// resource this crate doesn't know about, are skipped.
+///
 /// [Screeps documentation](https://docs.screeps.com/api/#Game.market.getAllOrders)
 ///
 /// [source]: https://github.com/screeps/engine/blob/f7a09e637c20689084fcf4eb43eacdfd51d31476/src/game/market.js#L37
 pub fn get_all_orders(filter: Option<&LodashFilter>) -> Vec<Order> {
     Market::get_all_orders(filter)
         .iter()
-        .map(Into::into)
+        .filter_map(|order| serde_wasm_bindgen::from_value(order).ok())
         .collect()
 }
 
+/// Get the [`Order`]s on the market matching an [`OrderFilter`].
+///
+/// The filter is applied by the engine, which only builds the orders that
+/// match, and can look up orders by resource type without checking every
+/// order ([source]).
+///
+/// [Screeps documentation](https://docs.screeps.com/api/#Game.market.getAllOrders)
+///
+/// [source]: https://github.com/screeps/engine/blob/f7a09e637c20689084fcf4eb43eacdfd51d31476/src/game/market.js#L37
+pub fn get_all_orders_filtered(filter: &OrderFilter) -> Vec<Order> {
+    let filter: LodashFilter = serde_wasm_bindgen::to_value(filter)
+        .expect("expected serializable order filter")
+        .unchecked_into();
+    get_all_orders(Some(&filter))
+}
+
 /// Get information about the price history on the market for the last 14
 /// days for a given resource as an [`Array`] of [`OrderHistoryRecord`]s, or
 /// for all resources if `None`. Warning: returns an empty [`Object`]
@@ -191,51 +211,65 @@ pub fn get_history(resource: Option<ResourceType>) -> Vec<OrderHistoryRecord> {
 /// Get an object with information about a specific order, in the same
 /// format as returned by [`get_all_orders`]
 ///
+/// Returns `None` if there's no order with the ID, or if the order can't be
+/// read into an [`Order`].
+///
 /// [Screeps documentation](https://docs.screeps.com/api/#Game.market.getOrderById)
 pub fn get_order_by_id(order_id: &str) -> Option<Order> {
     let order_id: JsString = order_id.into();
 
-    Market::get_order_by_id(&order_id)
+    let order = Market::get_order_by_id(&order_id);
+    if order.is_null() || order.is_undefined() {
+        return None;
+    }
+
+    serde_wasm_bindgen::from_value(order).ok()
 }
 
-#[wasm_bindgen]
-extern "C" {
-    /// An object that represents an order on the market.
-    #[wasm_bindgen]
-    #[derive(Debug)]
-    pub type Order;
+/// An order on the market, read into Rust memory from the object returned by
+/// [`get_all_orders`] or [`get_order_by_id`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
     /// The order ID, which can be used to retrieve the order, or execute a
-    /// trade using [`MarketInfo::deal`].
-    #[wasm_bindgen(method, getter)]
-    pub fn id(this: &Order) -> JsString;
+    /// trade using [`deal`].
+    pub id: String,
     /// Tick of order creation, `None` for intershard orders.
-    #[wasm_bindgen(method, getter)]
-    pub fn created(this: &Order) -> Option<u32>;
-    // todo should be u64 but seems to panic at the moment, follow up
+    pub created: Option<u32>,
     /// Timestamp of order creation in milliseconds since epoch.
-    #[wasm_bindgen(method, getter = createdTimestamp)]
-    pub fn created_timestamp(this: &Order) -> f64;
+    pub created_timestamp: u64,
     /// The [`OrderType`] of the order (whether the owner is looking to buy or
     /// sell the given resource).
-    #[wasm_bindgen(method, getter = type)]
-    pub fn order_type(this: &Order) -> OrderType;
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
     /// The resource type this order is for.
-    #[wasm_bindgen(method, getter = resourceType)]
-    pub fn resource_type(this: &Order) -> MarketResourceType;
+    pub resource_type: MarketResourceType,
     /// Room that owns the order, `None` for intershard orders.
-    #[wasm_bindgen(method, getter = roomName)]
-    pub fn room_name(this: &Order) -> Option<JsString>;
+    pub room_name: Option<RoomName>,
     /// The amount of resource currently ready to be traded (loaded in the
     /// terminal).
-    #[wasm_bindgen(method, getter)]
-    pub fn amount(this: &Order) -> u32;
+    pub amount: u32,
     /// The total remaining amount of the resource to be traded before this
     /// order has been completely filled and removed.
-    #[wasm_bindgen(method, getter = remainingAmount)]
-    pub fn remaining_amount(this: &Order) -> u32;
+    pub remaining_amount: u32,
     /// Price of the order per unit of the resource the order is for.
-    #[wasm_bindgen(method, getter)]
-    pub fn price(this: &Order) -> f64;
+    pub price: f64,
+}
+
+/// A filter for [`get_all_orders_filtered`], matching only the orders with
+/// each of the properties that are set.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFilter {
+    /// Only match orders for this resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<MarketResourceType>,
+    /// Only match buy or sell orders.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<OrderType>,
+    /// Only match orders owned by this room.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub room_name: Option<RoomName>,
 }
 
 // todo docs
@@ -357,3 +391,125 @@ extern "C" {
     #[wasm_bindgen(method, getter = stddevPrice)]
     pub fn stddev_price(this: &OrderHistoryRecord) -> f64;
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Order, OrderFilter};
+    use crate::constants::{IntershardResourceType, MarketResourceType, OrderType, ResourceType};
+
+    #[test]
+    fn order_from_engine_object() {
+        let js_object = serde_json::json!({
+            "id": "55c34a6b5be41a0a6e80c68b",
+            "created": 13_131_117,
+            "createdTimestamp": 1_700_000_000_000_u64,
+            "active": true,
+            "type": "sell",
+            "resourceType": "O",
+            "roomName": "W1N1",
+            "amount": 15_821,
+            "remainingAmount": 30_000,
+            "price": 2.95,
+        });
+
+        let order: Order = serde_json::from_value(js_object).unwrap();
+        assert_eq!(
+            order,
+            Order {
+                id: "55c34a6b5be41a0a6e80c68b".to_owned(),
+                created: Some(13_131_117),
+                created_timestamp: 1_700_000_000_000,
+                order_type: OrderType::Sell,
+                resource_type: MarketResourceType::Resource(ResourceType::Oxygen),
+                room_name: Some("W1N1".parse().unwrap()),
+                amount: 15_821,
+                remaining_amount: 30_000,
+                price: 2.95,
+            }
+        );
+    }
+
+    #[test]
+    fn intershard_order_without_room() {
+        // intershard orders have no creation tick or room
+        let js_object = serde_json::json!({
+            "id": "5d9ae4fa03fdb80c6a3eb9fa",
+            "createdTimestamp": 1_700_000_000_000_u64,
+            "type": "buy",
+            "resourceType": "pixel",
+            "amount": 100,
+            "remainingAmount": 100,
+            "price": 25_000.0,
+        });
+
+        let order: Order = serde_json::from_value(js_object).unwrap();
+        assert_eq!(order.created, None);
+        assert_eq!(order.room_name, None);
+        assert_eq!(order.order_type, OrderType::Buy);
+        assert_eq!(
+            order.resource_type,
+            MarketResourceType::IntershardResource(IntershardResourceType::Pixel)
+        );
+    }
+
+    #[test]
+    fn order_filter_object() {
+        let filter = OrderFilter {
+            resource_type: Some(MarketResourceType::Resource(ResourceType::Energy)),
+            order_type: Some(OrderType::Buy),
+            room_name: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap(),
+            serde_json::json!({ "resourceType": "energy", "type": "buy" })
+        );
+
+        let filter = OrderFilter {
+            room_name: Some("W1N1".parse().unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap(),
+            serde_json::json!({ "roomName": "W1N1" })
+        );
+        assert_eq!(
+            serde_json::to_value(OrderFilter::default()).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn filtered_orders_from_engine() {
+        // orders returned for `{ resourceType: "energy", type: "buy" }`
+        let js_array = serde_json::json!([
+            {
+                "id": "55c34a6b5be41a0a6e80c68b",
+                "created": 13_131_117,
+                "createdTimestamp": 1_700_000_000_000_u64,
+                "type": "buy",
+                "resourceType": "energy",
+                "roomName": "W1N1",
+                "amount": 10_000,
+                "remainingAmount": 50_000,
+                "price": 1.5,
+            },
+            {
+                "id": "55c34a6b5be41a0a6e80c68c",
+                "created": 13_131_200,
+                "createdTimestamp": 1_700_000_100_000_u64,
+                "type": "buy",
+                "resourceType": "energy",
+                "roomName": "E5S5",
+                "amount": 0,
+                "remainingAmount": 20_000,
+                "price": 1.25,
+            },
+        ]);
+
+        let orders: Vec<Order> = serde_json::from_value(js_array).unwrap();
+        assert_eq!(orders.len(), 2);
+        assert!(orders.iter().all(|order| order.order_type == OrderType::Buy
+            && order.resource_type == MarketResourceType::Resource(ResourceType::Energy)));
+        assert_eq!(orders[1].room_name, Some("E5S5".parse().unwrap()));
+    }
+}
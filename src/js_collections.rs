@@ -42,12 +42,24 @@ pub struct JsHashMap<K, V> {
     _phantom: PhantomData<(K, V)>,
 }
 
+impl<K, V> JsHashMap<K, V> {
+    /// Gets the number of entries in the map.
+    pub fn len(&self) -> usize {
+        Object::keys::<JsValue>(self.map.unchecked_ref()).length() as usize
+    }
+
+    /// Whether the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 impl<K, V> JsHashMap<K, V>
 where
     K: JsCollectionFromValue,
 {
-    pub fn keys(&self) -> impl Iterator<Item = K> {
-        let array = Object::keys(self.map.unchecked_ref());
+    pub fn keys(&self) -> OwnedArrayIter<K> {
+        let array = Object::keys::<JsValue>(self.map.unchecked_ref());
 
         OwnedArrayIter::new(array)
     }
@@ -57,13 +69,55 @@ impl<K, V> JsHashMap<K, V>
 where
     V: JsCollectionFromValue,
 {
-    pub fn values(&self) -> impl Iterator<Item = V> {
+    pub fn values(&self) -> OwnedArrayIter<V> {
         let array = Object::values(self.map.unchecked_ref());
 
         OwnedArrayIter::new(array)
     }
 }
 
+impl<K, V> JsHashMap<K, V>
+where
+    K: JsCollectionFromValue,
+    V: JsCollectionFromValue,
+{
+    /// Gets an iterator over the key-value pairs of the map.
+    ///
+    /// The pairs are fetched from JavaScript in a single call, but each one is
+    /// only converted into Rust types as the iterator reaches it.
+    pub fn entries(&self) -> OwnedArrayIter<(K, V)> {
+        let array = Object::entries(self.map.unchecked_ref());
+
+        OwnedArrayIter::new(array)
+    }
+}
+
+impl<K, V> IntoIterator for JsHashMap<K, V>
+where
+    K: JsCollectionFromValue,
+    V: JsCollectionFromValue,
+{
+    type Item = (K, V);
+    type IntoIter = OwnedArrayIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries()
+    }
+}
+
+impl<K, V> IntoIterator for &JsHashMap<K, V>
+where
+    K: JsCollectionFromValue,
+    V: JsCollectionFromValue,
+{
+    type Item = (K, V);
+    type IntoIter = OwnedArrayIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries()
+    }
+}
+
 impl<K, V> JsHashMap<K, V>
 where
     K: JsCollectionIntoValue,
@@ -111,6 +165,11 @@ impl<K, V> From<JsValue> for JsHashMap<K, V> {
     }
 }
 
+/// Iterator over the contents of a JavaScript [`Array`], converting each
+/// element into `T` only when it's reached.
+///
+/// Nothing is collected into Rust memory up front, so adaptors like
+/// [`Iterator::find`] stop converting elements as soon as they have a result.
 #[derive(Debug, Clone)]
 pub struct OwnedArrayIter<T> {
     range: std::ops::Range<u32>,
@@ -297,6 +356,18 @@ impl JsCollectionFromValue for String {
     }
 }
 
+impl<K, V> JsCollectionFromValue for (K, V)
+where
+    K: JsCollectionFromValue,
+    V: JsCollectionFromValue,
+{
+    fn from_value(val: JsValue) -> (K, V) {
+        let entry: Array = val.unchecked_into();
+
+        (K::from_value(entry.get(0)), V::from_value(entry.get(1)))
+    }
+}
+
 impl JsCollectionIntoValue for u8 {
     fn into_value(self) -> JsValue {
         JsValue::from_f64(self as f64)
@@ -88,6 +88,12 @@
 //! code values are in the expected ranges skipping checks, and risks undefined
 //! behavior if they are not.
 //!
+//! ## `debug-return-conversion`
+//!
+//! Enables `unsafe-return-conversion`, but checks that return codes are in the
+//! expected ranges when debug assertions are enabled, panicking if they are
+//! not. Release builds skip the checks, as with `unsafe-return-conversion`.
+//!
 //! ## `mmo`
 //!
 //! Enables the `generate-pixel` and `inter-shard-memory` features, which are
@@ -1,12 +1,20 @@
 //! Pure-data structures relating to Screeps.
 use std::ops::Range;
 
+mod algo;
+mod base_planning;
 mod cost_matrix;
 mod lodash_filter;
+mod logistics;
+mod min_cut;
 mod object_id;
+mod observer_scheduler;
 mod position;
 mod room_coordinate;
+mod room_data;
 mod room_name;
+mod room_tile_bitset;
+mod serialized_path;
 mod terrain;
 
 /// Represents two constants related to room names.
@@ -31,6 +39,7 @@ const VALID_WORLD_POSITIONS: Range<i32> =
 use crate::ROOM_SIZE;
 
 pub use self::{
-    cost_matrix::*, lodash_filter::*, object_id::*, position::*, room_coordinate::*, room_name::*,
-    terrain::*,
+    algo::*, base_planning::*, cost_matrix::*, lodash_filter::*, logistics::*, min_cut::*,
+    object_id::*, observer_scheduler::*, position::*, room_coordinate::*, room_data::*,
+    room_name::*, room_tile_bitset::*, serialized_path::*, terrain::*,
 };
@@ -0,0 +1,410 @@
+//! Algorithms over room data, for use in room and base planning as well as
+//! movement decisions.
+use crate::constants::{Direction, Terrain, ROOM_SIZE};
+
+use super::{LocalRoomTerrain, Position, RoomCoordinate, RoomDataArray, RoomXY};
+
+/// Computes, for each tile in the room, the distance to the nearest wall or
+/// room edge.
+///
+/// Distance is measured by range (Chebyshev distance), with the tiles just
+/// beyond the edge of the room treated as walls; wall tiles have a distance of
+/// 0, and open tiles on the edge of the room have a distance of 1. The tiles
+/// with the greatest distance are at the center of the largest open areas in
+/// the room.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{distance_transform, LocalRoomTerrain, RoomXY};
+///
+/// // an open room with no walls
+/// let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; 2500]));
+/// let distances = distance_transform(&terrain);
+///
+/// assert_eq!(distances[RoomXY::try_from((0, 20)).unwrap()], 1);
+/// assert_eq!(distances[RoomXY::try_from((24, 24)).unwrap()], 25);
+/// ```
+pub fn distance_transform(terrain: &LocalRoomTerrain) -> RoomDataArray<u8> {
+    let mut distances = RoomDataArray::new(0);
+
+    // the first pass carries distances down and to the right, from the
+    // neighbors above and to the left of each tile...
+    for y in coordinates() {
+        for x in coordinates() {
+            let xy = RoomXY { x, y };
+            if terrain.get(xy) != Terrain::Wall {
+                let nearest = [(-1, -1), (0, -1), (1, -1), (-1, 0)]
+                    .into_iter()
+                    .map(|offset| neighbor_distance(&distances, xy, offset))
+                    .min()
+                    .unwrap_or(0);
+                distances[xy] = nearest + 1;
+            }
+        }
+    }
+
+    // ...and the second pass carries them back up and to the left, from the
+    // neighbors below and to the right
+    for y in coordinates().rev() {
+        for x in coordinates().rev() {
+            let xy = RoomXY { x, y };
+            if terrain.get(xy) != Terrain::Wall {
+                let nearest = [(1, 1), (0, 1), (-1, 1), (1, 0)]
+                    .into_iter()
+                    .map(|offset| neighbor_distance(&distances, xy, offset))
+                    .min()
+                    .unwrap_or(0);
+                distances[xy] = distances[xy].min(nearest + 1);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Finds the walkable tile next to `from` which is furthest from all of the
+/// given threats, such as for kiting away from enemy creeps.
+///
+/// Tiles are compared by their range to the closest threat, with ties broken
+/// by the total squared straight-line distance to all threats, then by
+/// [`Direction`] order. Returns
+/// `None` if there are no threats, or if every neighboring tile is a wall. The
+/// chosen tile may still be closer to a threat than `from` is, if no
+/// neighboring tile is any further away.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{safe_retreat_tile, LocalRoomTerrain, RoomXY};
+///
+/// let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; 2500]));
+/// let from = RoomXY::try_from((10, 10)).unwrap();
+/// let threat = RoomXY::try_from((12, 12)).unwrap();
+///
+/// assert_eq!(
+///     safe_retreat_tile(from, &[threat], &terrain),
+///     Some(RoomXY::try_from((9, 9)).unwrap())
+/// );
+/// ```
+pub fn safe_retreat_tile(
+    from: RoomXY,
+    threats: &[RoomXY],
+    terrain: &LocalRoomTerrain,
+) -> Option<RoomXY> {
+    if threats.is_empty() {
+        return None;
+    }
+
+    let mut best: Option<((u8, u32), RoomXY)> = None;
+    for direction in enum_iterator::all::<Direction>() {
+        let Some(neighbor) = from.checked_add_direction(direction) else {
+            continue;
+        };
+        if terrain.get(neighbor) == Terrain::Wall {
+            continue;
+        }
+
+        let score = (
+            threats
+                .iter()
+                .map(|&threat| range(neighbor, threat))
+                .min()
+                .unwrap_or(0),
+            threats
+                .iter()
+                .map(|&threat| squared_distance(neighbor, threat))
+                .sum(),
+        );
+        match best {
+            Some((best_score, _)) if best_score >= score => {}
+            _ => best = Some((score, neighbor)),
+        }
+    }
+
+    best.map(|(_, xy)| xy)
+}
+
+/// Checks whether a tile is walled off from all of the room's exits, such that
+/// no path along open tiles leads from it to an exit tile on the edge of the
+/// room.
+///
+/// This flood fills outward from `xy`, stopping as soon as an open exit tile
+/// is reached; it never visits a tile more than once, so at most the whole
+/// room is searched. Corners are never exits, as with [`RoomXY::edge`]. An
+/// exit tile is never enclosed, unless it's a wall. A wall tile is otherwise
+/// treated like any other tile, enclosed if none of its open neighbors can
+/// reach an exit.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{is_enclosed, LocalRoomTerrain, RoomXY};
+///
+/// // an open room with no walls
+/// let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; 2500]));
+///
+/// assert!(!is_enclosed(RoomXY::try_from((25, 25)).unwrap(), &terrain));
+/// ```
+pub fn is_enclosed(xy: RoomXY, terrain: &LocalRoomTerrain) -> bool {
+    let mut visited = RoomDataArray::new(false);
+    visited[xy] = true;
+    let mut stack = vec![xy];
+
+    while let Some(current) = stack.pop() {
+        if current.edge().is_some() && terrain.get(current) != Terrain::Wall {
+            return false;
+        }
+
+        for direction in enum_iterator::all::<Direction>() {
+            if let Some(neighbor) = current.checked_add_direction(direction) {
+                if !visited[neighbor] && terrain.get(neighbor) != Terrain::Wall {
+                    visited[neighbor] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    true
+}
+
+/// Estimates the number of moves a creep needs to get from one position to
+/// another, without searching for a path.
+///
+/// The estimate is the range between the positions in world coordinates,
+/// which never overestimates the length of a path, since each move changes
+/// each coordinate by at most 1. This holds across rooms: moving onto an exit
+/// tile and through to the next room costs the same single move as any other
+/// step, so positions on either side of a room border are 1 apart. Walls,
+/// swamps, and detours around impassable room borders all make real paths
+/// longer.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{local::estimate_path_length, Position};
+///
+/// let from = Position::from_world_coords(10, 10);
+/// // two rooms to the right, and a few tiles down
+/// let to = Position::from_world_coords(110, 15);
+///
+/// assert_eq!(estimate_path_length(from, to), 100);
+/// ```
+pub fn estimate_path_length(from: Position, to: Position) -> u32 {
+    from.get_range_to(to)
+}
+
+fn range(a: RoomXY, b: RoomXY) -> u8 {
+    a.x.u8().abs_diff(b.x.u8()).max(a.y.u8().abs_diff(b.y.u8()))
+}
+
+fn squared_distance(a: RoomXY, b: RoomXY) -> u32 {
+    let dx = u32::from(a.x.u8().abs_diff(b.x.u8()));
+    let dy = u32::from(a.y.u8().abs_diff(b.y.u8()));
+    dx * dx + dy * dy
+}
+
+fn coordinates() -> impl DoubleEndedIterator<Item = RoomCoordinate> {
+    RoomCoordinate::range(
+        RoomCoordinate::new_const::<0>(),
+        RoomCoordinate::new_const::<{ ROOM_SIZE - 1 }>(),
+    )
+}
+
+/// The distance recorded for a neighboring tile, where tiles outside of the
+/// room count as walls.
+fn neighbor_distance(distances: &RoomDataArray<u8>, xy: RoomXY, offset: (i8, i8)) -> u8 {
+    xy.checked_add(offset)
+        .map_or(0, |neighbor| distances[neighbor])
+}
+
+#[cfg(test)]
+mod test {
+    use super::{distance_transform, estimate_path_length, is_enclosed, safe_retreat_tile};
+    use crate::local::{
+        xy_to_terrain_index, LocalRoomTerrain, Position, RoomCoordinate, RoomName, RoomXY,
+        ROOM_AREA,
+    };
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::try_from((x, y)).unwrap()
+    }
+
+    #[test]
+    fn distances_to_walls_and_edges() {
+        // open room with walls at (10, 10) and along x = 30 for y in 5..=7:
+        //
+        //   x: 27 28 29 30 31 32 33
+        // y=6:  3  2  1  #  1  2  3
+        let mut bits = Box::new([0; ROOM_AREA]);
+        bits[xy_to_terrain_index(xy(10, 10))] = 1;
+        for y in 5..=7 {
+            bits[xy_to_terrain_index(xy(30, y))] = 1;
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+
+        let distances = distance_transform(&terrain);
+
+        // walls
+        assert_eq!(distances[xy(10, 10)], 0);
+        assert_eq!(distances[xy(30, 6)], 0);
+        // around the single wall tile
+        assert_eq!(distances[xy(9, 9)], 1);
+        assert_eq!(distances[xy(11, 10)], 1);
+        assert_eq!(distances[xy(12, 12)], 2);
+        assert_eq!(distances[xy(13, 8)], 3);
+        // beside the wall segment, closer than the top edge
+        let row: Vec<u8> = (27..=33).map(|x| distances[xy(x, 6)]).collect();
+        assert_eq!(row, vec![3, 2, 1, 0, 1, 2, 3]);
+        // at and near the room edges
+        assert_eq!(distances[xy(0, 0)], 1);
+        assert_eq!(distances[xy(49, 25)], 1);
+        assert_eq!(distances[xy(25, 1)], 2);
+        assert_eq!(distances[xy(47, 47)], 3);
+        // the center is furthest from everything
+        assert_eq!(distances[xy(24, 24)], 14);
+
+        // every tile matches a brute-force search for the nearest wall or edge
+        for (pos, &distance) in distances.iter() {
+            let (x, y): (u8, u8) = pos.into();
+            let (x, y) = (i32::from(x), i32::from(y));
+            let to_edge = [x + 1, y + 1, 50 - x, 50 - y].into_iter().min().unwrap();
+            let to_wall = [(10, 10), (30, 5), (30, 6), (30, 7)]
+                .into_iter()
+                .map(|(wx, wy): (i32, i32)| (wx - x).abs().max((wy - y).abs()))
+                .min()
+                .unwrap();
+            assert_eq!(i32::from(distance), to_edge.min(to_wall), "at {pos}");
+        }
+    }
+
+    #[test]
+    fn retreat_from_single_threat() {
+        // the threat is diagonally up and to the left, and the tile diagonally
+        // away from it is walled off; of the tiles 2 away from the threat,
+        // those directly below and to the right are the furthest in a straight
+        // line
+        let mut bits = Box::new([0; ROOM_AREA]);
+        bits[xy_to_terrain_index(xy(21, 21))] = 1;
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+
+        let from = xy(20, 20);
+        let threat = xy(19, 19);
+        // (21, 20) and (20, 21) are both 2 away, but Direction::Right comes
+        // before Direction::Bottom
+        assert_eq!(
+            safe_retreat_tile(from, &[threat], &terrain),
+            Some(xy(21, 20))
+        );
+
+        // without the wall, the diagonal is the furthest tile
+        let open = LocalRoomTerrain::new_from_bits(Box::new([0; ROOM_AREA]));
+        assert_eq!(safe_retreat_tile(from, &[threat], &open), Some(xy(21, 21)));
+
+        assert_eq!(safe_retreat_tile(from, &[], &open), None);
+    }
+
+    #[test]
+    fn retreat_between_threats() {
+        let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; ROOM_AREA]));
+
+        // threats to the left and right leave up and down as the only tiles
+        // which keep a range of 3 to both; up is checked first
+        let threats = [xy(7, 10), xy(13, 10)];
+        assert_eq!(
+            safe_retreat_tile(xy(10, 10), &threats, &terrain),
+            Some(xy(10, 9))
+        );
+
+        // in a corner, the only ways out are towards the threats
+        let mut bits = Box::new([1; ROOM_AREA]);
+        bits[xy_to_terrain_index(xy(0, 0))] = 0;
+        bits[xy_to_terrain_index(xy(1, 0))] = 0;
+        let walled = LocalRoomTerrain::new_from_bits(bits);
+        assert_eq!(
+            safe_retreat_tile(xy(0, 0), &[xy(3, 0)], &walled),
+            Some(xy(1, 0))
+        );
+        assert_eq!(safe_retreat_tile(xy(5, 5), &[xy(3, 0)], &walled), None);
+    }
+
+    #[test]
+    fn enclosed_pocket() {
+        // a ring of walls from (10, 10) to (14, 14), enclosing a 3x3 pocket,
+        // and walls filling the top left corner of the room apart from (1, 1)
+        let mut bits = Box::new([0; ROOM_AREA]);
+        for i in 10..=14 {
+            for wall in [xy(i, 10), xy(i, 14), xy(10, i), xy(14, i)] {
+                bits[xy_to_terrain_index(wall)] = 1;
+            }
+        }
+        for y in 0..=4 {
+            for x in 0..=(4 - y) {
+                if (x, y) != (1, 1) {
+                    bits[xy_to_terrain_index(xy(x, y))] = 1;
+                }
+            }
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits.clone());
+
+        // inside the pocket
+        assert!(is_enclosed(xy(12, 12), &terrain));
+        assert!(is_enclosed(xy(11, 13), &terrain));
+        // a wall in the ring borders both the pocket and the outside
+        assert!(!is_enclosed(xy(10, 10), &terrain));
+        // outside the pocket, but open to the rest of the room
+        assert!(!is_enclosed(xy(9, 9), &terrain));
+        assert!(!is_enclosed(xy(0, 5), &terrain));
+        assert!(!is_enclosed(xy(30, 30), &terrain));
+        // a pocket next to walled-off edge tiles
+        assert!(is_enclosed(xy(1, 1), &terrain));
+        // an open corner doesn't make the pocket reach an exit
+        bits[xy_to_terrain_index(xy(0, 0))] = 0;
+        let terrain = LocalRoomTerrain::new_from_bits(bits.clone());
+        assert!(is_enclosed(xy(1, 1), &terrain));
+        assert!(is_enclosed(xy(0, 0), &terrain));
+
+        // a single gap in the ring opens the pocket
+        bits[xy_to_terrain_index(xy(12, 14))] = 0;
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+        assert!(!is_enclosed(xy(12, 12), &terrain));
+    }
+
+    #[test]
+    fn path_length_estimates() {
+        let pos = |x, y, room: &str| {
+            Position::new(
+                RoomCoordinate::new(x).unwrap(),
+                RoomCoordinate::new(y).unwrap(),
+                RoomName::new(room).unwrap(),
+            )
+        };
+
+        // within a room, the larger of the x and y distances
+        assert_eq!(
+            estimate_path_length(pos(10, 10, "W1N1"), pos(10, 10, "W1N1")),
+            0
+        );
+        assert_eq!(
+            estimate_path_length(pos(10, 10, "W1N1"), pos(13, 40, "W1N1")),
+            30
+        );
+
+        // one move across a room border
+        assert_eq!(
+            estimate_path_length(pos(49, 20, "W2N1"), pos(0, 20, "W1N1")),
+            1
+        );
+        // three rooms along x, through W0N1 and E0N1
+        assert_eq!(
+            estimate_path_length(pos(25, 25, "W1N1"), pos(25, 25, "E1N1")),
+            150
+        );
+        // rooms apart along both axes: 70 tiles along x and 90 along y
+        assert_eq!(
+            estimate_path_length(pos(40, 5, "W3N3"), pos(10, 45, "W1N2")),
+            90
+        );
+    }
+}
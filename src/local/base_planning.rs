@@ -0,0 +1,437 @@
+//! Helpers for planning bases, from choosing rooms to expand into to laying
+//! out structures in a room.
+use std::{cmp::Reverse, collections::VecDeque, iter};
+
+use crate::constants::{Direction, ResourceType, StructureType, Terrain, ROOM_SIZE};
+
+use super::{
+    distance_transform, LocalRoomTerrain, RoomCoordinate, RoomDataArray, RoomXY, ROOM_AREA,
+};
+
+/// The range from which a creep can upgrade a controller.
+const UPGRADE_RANGE: u8 = 3;
+
+/// The least [`distance_transform`] value of a tile chosen by [`base_anchor`],
+/// leaving at least a 5x5 square of open tiles centered on it.
+pub const BASE_ANCHOR_MIN_OPENNESS: u8 = 3;
+
+/// Score added by [`expansion_score`] for each source in the room.
+pub const EXPANSION_SOURCE_WEIGHT: f64 = 20.;
+/// Score added by [`expansion_score`] when the room has a mineral.
+pub const EXPANSION_MINERAL_WEIGHT: f64 = 5.;
+/// Score added by [`expansion_score`] for a room with no walls or swamps,
+/// scaled down by the fraction of the room that's blocked.
+pub const EXPANSION_OPEN_TERRAIN_WEIGHT: f64 = 30.;
+/// Score subtracted by [`expansion_score`] for each side of the room with an
+/// exit.
+pub const EXPANSION_EXIT_SIDE_PENALTY: f64 = 3.;
+/// Score subtracted by [`expansion_score`] for each room of distance from
+/// home.
+pub const EXPANSION_DISTANCE_PENALTY: f64 = 4.;
+
+/// Scores a room as a candidate for expansion, with higher scores being
+/// better.
+///
+/// The score is the sum of:
+/// - [`EXPANSION_SOURCE_WEIGHT`] for each of the room's `sources`
+/// - [`EXPANSION_MINERAL_WEIGHT`] if the room has a `mineral`
+/// - [`EXPANSION_OPEN_TERRAIN_WEIGHT`] multiplied by the room's openness, where
+///   plains count fully, swamps count half, and walls don't count
+///
+/// minus:
+/// - [`EXPANSION_EXIT_SIDE_PENALTY`] for each side of the room with any exit
+///   tiles, as each is another side to defend
+/// - [`EXPANSION_DISTANCE_PENALTY`] for each room of `distance` from home
+///
+/// The result is only meaningful relative to the scores of other rooms.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{
+///     constants::ResourceType,
+///     local::{expansion_score, LocalRoomTerrain},
+/// };
+///
+/// let open_room = LocalRoomTerrain::new_from_bits(Box::new([0; 2500]));
+///
+/// // 2 sources, a mineral, open terrain, 4 exit sides, and 3 rooms away
+/// let score = expansion_score(&open_room, 2, Some(ResourceType::Keanium), 3);
+/// assert_eq!(score, 40. + 5. + 30. - 12. - 12.);
+/// ```
+pub fn expansion_score(
+    terrain: &LocalRoomTerrain,
+    sources: u32,
+    mineral: Option<ResourceType>,
+    distance: u32,
+) -> f64 {
+    let min = RoomCoordinate::new_const::<0>();
+    let max = RoomCoordinate::new_const::<{ ROOM_SIZE - 1 }>();
+    let coords = || RoomCoordinate::range(min, max);
+
+    let openness: f64 = coords()
+        .flat_map(|y| coords().map(move |x| RoomXY { x, y }))
+        .map(|xy| match terrain.get(xy) {
+            Terrain::Plain => 1.,
+            Terrain::Swamp => 0.5,
+            Terrain::Wall => 0.,
+        })
+        .sum::<f64>()
+        / ROOM_AREA as f64;
+
+    let walkable = |xy: RoomXY| terrain.get(xy) != Terrain::Wall;
+    let exit_sides = [
+        coords().any(|x| walkable(RoomXY { x, y: min })),
+        coords().any(|x| walkable(RoomXY { x, y: max })),
+        coords().any(|y| walkable(RoomXY { x: min, y })),
+        coords().any(|y| walkable(RoomXY { x: max, y })),
+    ]
+    .into_iter()
+    .filter(|&has_exit| has_exit)
+    .count();
+
+    let mineral_score = if mineral.is_some() {
+        EXPANSION_MINERAL_WEIGHT
+    } else {
+        0.
+    };
+
+    f64::from(sources) * EXPANSION_SOURCE_WEIGHT
+        + mineral_score
+        + openness * EXPANSION_OPEN_TERRAIN_WEIGHT
+        - exit_sides as f64 * EXPANSION_EXIT_SIDE_PENALTY
+        - f64::from(distance) * EXPANSION_DISTANCE_PENALTY
+}
+
+/// Places a stamp of structures at an anchor position, returning the absolute
+/// position of each structure in the stamp.
+///
+/// Each entry of the stamp is an `(x, y)` offset from the anchor paired with
+/// the type of structure to place there. Entries which would fall outside of
+/// the room are dropped; the remaining placements are returned in the same
+/// order as the stamp.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{constants::StructureType, local::apply_stamp, RoomXY};
+///
+/// let stamp = [
+///     ((0, 0), StructureType::Spawn),
+///     ((-1, 1), StructureType::Extension),
+///     ((1, 1), StructureType::Extension),
+/// ];
+/// let anchor = RoomXY::try_from((10, 10)).unwrap();
+///
+/// assert_eq!(
+///     apply_stamp(anchor, &stamp),
+///     vec![
+///         (RoomXY::try_from((10, 10)).unwrap(), StructureType::Spawn),
+///         (RoomXY::try_from((9, 11)).unwrap(), StructureType::Extension),
+///         (RoomXY::try_from((11, 11)).unwrap(), StructureType::Extension),
+///     ]
+/// );
+/// ```
+pub fn apply_stamp(
+    anchor: RoomXY,
+    stamp: &[((i8, i8), StructureType)],
+) -> Vec<(RoomXY, StructureType)> {
+    stamp
+        .iter()
+        .filter_map(|&(offset, ty)| anchor.checked_add(offset).map(|xy| (xy, ty)))
+        .collect()
+}
+
+/// Chooses a tile for a creep to stand on while upgrading a controller.
+///
+/// Candidates are the walkable tiles within range 3 of the controller, which
+/// is the range of [`Creep::upgrade_controller`], excluding tiles on the room
+/// edge where the creep could be pulled into the neighboring room. With a
+/// container, tiles closer to it are preferred, so a tile on or adjacent to
+/// it lets the upgrader withdraw energy without moving. Among equally good
+/// tiles, the one closest to the controller wins, with further ties going to
+/// the first in the order of [`RoomXY::spiral_from`].
+///
+/// Returns `None` if there's no walkable tile in range of the controller.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{best_upgrade_tile, LocalRoomTerrain, RoomXY};
+///
+/// let xy = |x, y| RoomXY::try_from((x, y)).unwrap();
+/// let open_room = LocalRoomTerrain::new_from_bits(Box::new([0; 2500]));
+///
+/// assert_eq!(best_upgrade_tile(xy(10, 10), None, &open_room), Some(xy(10, 9)));
+/// assert_eq!(
+///     best_upgrade_tile(xy(10, 10), Some(xy(13, 10)), &open_room),
+///     Some(xy(13, 10))
+/// );
+/// ```
+///
+/// [`Creep::upgrade_controller`]: crate::objects::Creep::upgrade_controller
+pub fn best_upgrade_tile(
+    controller_xy: RoomXY,
+    container_xy: Option<RoomXY>,
+    terrain: &LocalRoomTerrain,
+) -> Option<RoomXY> {
+    let range = |a: RoomXY, b: RoomXY| a.x.u8().abs_diff(b.x.u8()).max(a.y.u8().abs_diff(b.y.u8()));
+
+    controller_xy
+        .spiral_from()
+        .take_while(|&xy| range(xy, controller_xy) <= UPGRADE_RANGE)
+        .filter(|&xy| xy != controller_xy && !xy.is_room_edge() && terrain.get(xy) != Terrain::Wall)
+        .min_by_key(|&xy| {
+            let container_range = container_xy.map_or(0, |container| range(xy, container));
+            (container_range, range(xy, controller_xy))
+        })
+}
+
+/// Chooses an anchor tile to center a base layout on, close to the room's
+/// sources and controller.
+///
+/// Candidates are tiles with a [`distance_transform`] value of at least
+/// [`BASE_ANCHOR_MIN_OPENNESS`], so there's open space around the anchor to
+/// build in. The chosen tile has the smallest total walking distance to each
+/// source and the controller, counting moves along walkable tiles, with ties
+/// going to the more open tile, then to the first in row-major order.
+///
+/// Returns `None` if no tile is open enough, or if none of the open tiles can
+/// reach every source and the controller.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{base_anchor, LocalRoomTerrain, RoomXY};
+///
+/// let xy = |x, y| RoomXY::try_from((x, y)).unwrap();
+/// let open_room = LocalRoomTerrain::new_from_bits(Box::new([0; 2500]));
+///
+/// // many tiles between these are equally close, so the most open of them, at
+/// // the middle of the room, wins
+/// assert_eq!(base_anchor(&[xy(10, 24)], xy(40, 24), &open_room), Some(xy(24, 24)));
+/// ```
+pub fn base_anchor(
+    sources: &[RoomXY],
+    controller: RoomXY,
+    terrain: &LocalRoomTerrain,
+) -> Option<RoomXY> {
+    let openness = distance_transform(terrain);
+    let distances: Vec<_> = sources
+        .iter()
+        .copied()
+        .chain(iter::once(controller))
+        .map(|target| walking_distances(target, terrain))
+        .collect();
+
+    openness
+        .iter()
+        .filter(|&(_, &open)| open >= BASE_ANCHOR_MIN_OPENNESS)
+        .filter_map(|(xy, &open)| {
+            let total = distances.iter().map(|d| d[xy]).sum::<Option<u32>>()?;
+            Some(((total, Reverse(open)), xy))
+        })
+        .min_by_key(|&(key, _)| key)
+        .map(|(_, xy)| xy)
+}
+
+/// The number of moves from `from` to each tile along walkable tiles, or
+/// `None` for tiles which can't be reached.
+///
+/// `from` itself doesn't need to be walkable, as sources and controllers
+/// aren't.
+fn walking_distances(from: RoomXY, terrain: &LocalRoomTerrain) -> RoomDataArray<Option<u32>> {
+    let mut distances = RoomDataArray::new(None);
+    distances[from] = Some(0);
+    let mut queue = VecDeque::from([(from, 0)]);
+
+    while let Some((current, distance)) = queue.pop_front() {
+        for direction in enum_iterator::all::<Direction>() {
+            if let Some(neighbor) = current.checked_add_direction(direction) {
+                if distances[neighbor].is_none() && terrain.get(neighbor) != Terrain::Wall {
+                    distances[neighbor] = Some(distance + 1);
+                    queue.push_back((neighbor, distance + 1));
+                }
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_stamp, base_anchor, best_upgrade_tile, expansion_score};
+    use crate::{
+        constants::{ResourceType, StructureType},
+        local::{xy_to_terrain_index, LocalRoomTerrain, RoomXY, ROOM_AREA},
+    };
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::try_from((x, y)).unwrap()
+    }
+
+    #[test]
+    fn stamp_overflowing_room_edge() {
+        let stamp = [
+            ((0, 0), StructureType::Storage),
+            ((-1, 0), StructureType::Link),
+            ((1, 0), StructureType::Terminal),
+            ((0, -1), StructureType::PowerSpawn),
+            ((0, 1), StructureType::Factory),
+            ((2, 2), StructureType::Nuker),
+        ];
+
+        // the anchor is next to the bottom right corner, so anything extending
+        // more than one tile right or down falls outside the room
+        assert_eq!(
+            apply_stamp(xy(48, 48), &stamp),
+            vec![
+                (xy(48, 48), StructureType::Storage),
+                (xy(47, 48), StructureType::Link),
+                (xy(49, 48), StructureType::Terminal),
+                (xy(48, 47), StructureType::PowerSpawn),
+                (xy(48, 49), StructureType::Factory),
+            ]
+        );
+
+        // anchored at the top left corner, all negative offsets are dropped
+        assert_eq!(
+            apply_stamp(xy(0, 0), &stamp),
+            vec![
+                (xy(0, 0), StructureType::Storage),
+                (xy(1, 0), StructureType::Terminal),
+                (xy(0, 1), StructureType::Factory),
+                (xy(2, 2), StructureType::Nuker),
+            ]
+        );
+    }
+
+    #[test]
+    fn expansion_score_sample_room() {
+        // the west half of the room is walls, and the northern 10 rows of the
+        // east half are swamp, leaving exits on every side but the west
+        let mut bits = Box::new([0; ROOM_AREA]);
+        for y in 0..50 {
+            for x in 0..50 {
+                let idx = xy_to_terrain_index(xy(x, y));
+                if x < 25 {
+                    bits[idx] = 1;
+                } else if y < 10 {
+                    bits[idx] = 2;
+                }
+            }
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+
+        // 1000 plains and 250 swamps make the room 45% open
+        let expected = 20. + 0.45 * 30. - 3. * 3. - 4.;
+        assert_eq!(expansion_score(&terrain, 1, None, 1), expected);
+        assert_eq!(expansion_score(&terrain, 1, None, 1), 20.5);
+
+        // a mineral and another source make the room better, and moving it
+        // further away makes it worse
+        assert_eq!(
+            expansion_score(&terrain, 2, Some(ResourceType::Catalyst), 1),
+            expected + 25.
+        );
+        assert_eq!(expansion_score(&terrain, 1, None, 6), expected - 20.);
+    }
+
+    #[test]
+    fn upgrade_tile_next_to_container() {
+        // the controller at (20, 20) has walls along its west side, and wall
+        // rows two tiles above and below it
+        let mut bits = Box::new([0; ROOM_AREA]);
+        for y in 17..=23 {
+            for x in 17..=19 {
+                bits[xy_to_terrain_index(xy(x, y))] = 1;
+            }
+        }
+        for x in 18..=22 {
+            bits[xy_to_terrain_index(xy(x, 18))] = 1;
+            bits[xy_to_terrain_index(xy(x, 22))] = 1;
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+        let controller = xy(20, 20);
+
+        // without a container, the first open tile next to the controller
+        assert_eq!(
+            best_upgrade_tile(controller, None, &terrain),
+            Some(xy(20, 19))
+        );
+
+        // the container itself is within range 3, so standing on it is best
+        assert_eq!(
+            best_upgrade_tile(controller, Some(xy(23, 20)), &terrain),
+            Some(xy(23, 20))
+        );
+
+        // for a container out of range, (23, 22) and (23, 23) are both as close
+        // to it as possible and at range 3 of the controller, so the spiral
+        // order picks between them
+        assert_eq!(
+            best_upgrade_tile(controller, Some(xy(25, 24)), &terrain),
+            Some(xy(23, 22))
+        );
+
+        // surrounded entirely by walls, there's nowhere to stand
+        let walls = LocalRoomTerrain::new_from_bits(Box::new([1; ROOM_AREA]));
+        assert_eq!(best_upgrade_tile(controller, None, &walls), None);
+    }
+
+    #[test]
+    fn upgrade_tile_avoids_room_edge() {
+        let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; ROOM_AREA]));
+        assert_eq!(
+            best_upgrade_tile(xy(2, 2), Some(xy(0, 0)), &terrain),
+            Some(xy(1, 1))
+        );
+    }
+
+    #[test]
+    fn base_anchor_between_sources_and_controller() {
+        // the room is walls except for an open area from (10, 10) to (30, 20),
+        // with the sources in its top corners and the controller at the middle
+        // of its bottom edge
+        let mut bits = Box::new([1; ROOM_AREA]);
+        for y in 10..=20 {
+            for x in 10..=30 {
+                bits[xy_to_terrain_index(xy(x, y))] = 0;
+            }
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+        let sources = [xy(10, 10), xy(30, 10)];
+
+        // open enough tiles span (12, 12) to (28, 18); along their bottom row,
+        // (18, 18) through (22, 18) are 20 moves from the sources together and
+        // 2 from the controller, which no other tile can beat
+        assert_eq!(
+            base_anchor(&sources, xy(20, 20), &terrain),
+            Some(xy(18, 18))
+        );
+
+        // a wall along x = 24 cuts off the right source
+        let mut bits = Box::new([1; ROOM_AREA]);
+        for y in 10..=20 {
+            for x in 10..=30 {
+                bits[xy_to_terrain_index(xy(x, y))] = 0;
+            }
+            bits[xy_to_terrain_index(xy(24, y))] = 1;
+        }
+        let split = LocalRoomTerrain::new_from_bits(bits);
+        assert_eq!(base_anchor(&sources, xy(20, 20), &split), None);
+
+        // without it, every tile on the diagonal from (12, 12) to (18, 18) is
+        // 10 moves from the left source and the controller, and the most open
+        // of them is in the middle of the left side
+        assert_eq!(
+            base_anchor(&sources[..1], xy(20, 20), &split),
+            Some(xy(15, 15))
+        );
+
+        // a room of walls has nowhere open to anchor a base
+        let walls = LocalRoomTerrain::new_from_bits(Box::new([1; ROOM_AREA]));
+        assert_eq!(base_anchor(&sources, xy(20, 20), &walls), None);
+    }
+}
@@ -1,13 +1,49 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    error::Error,
+    fmt,
+    ops::{Index, IndexMut},
+};
 
+use js_sys::Array;
 use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
 
 use crate::{
     objects::CostMatrix,
     traits::{CostMatrixGet, CostMatrixSet},
 };
 
-use super::{linear_index_to_xy, xy_to_linear_index, Position, RoomXY, ROOM_AREA};
+use super::{linear_index_to_xy, xy_to_linear_index, Position, RoomName, RoomXY, ROOM_AREA};
+
+/// The number of integers in the serialized form of a cost matrix, each of
+/// which holds the costs of four tiles.
+const SERIALIZED_LEN: usize = ROOM_AREA / 4;
+
+/// Error returned when deserializing a [`LocalCostMatrix`] from data not in
+/// the format produced by [`CostMatrix::serialize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SerializedCostMatrixError {
+    /// The value wasn't an array of unsigned 32-bit integers.
+    InvalidValue,
+    /// The array contained this number of elements, rather than 625.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for SerializedCostMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SerializedCostMatrixError::InvalidValue => {
+                write!(f, "serialized cost matrix must be an array of integers")
+            }
+            SerializedCostMatrixError::InvalidLength(len) => write!(
+                f,
+                "serialized cost matrix must have {SERIALIZED_LEN} elements, found {len}"
+            ),
+        }
+    }
+}
+
+impl Error for SerializedCostMatrixError {}
 
 /// A matrix of pathing costs for a room, stored in Rust memory.
 ///
@@ -50,6 +86,40 @@ impl LocalCostMatrix {
         self[xy]
     }
 
+    /// Sets the cost of each listed position, in order, so later entries for
+    /// the same position take precedence.
+    pub fn set_many(&mut self, entries: &[(RoomXY, u8)]) {
+        for &(xy, val) in entries {
+            self[xy] = val;
+        }
+    }
+
+    /// Resets the cost of every position to 0, allowing the matrix to be
+    /// reused without reallocating.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// Creates a copy of `base` with the tiles of the given creeps in `room`
+    /// raised to `cost`, for pathing around creeps.
+    ///
+    /// Creeps in other rooms are ignored, and tiles which already cost more
+    /// than `cost` in `base`, like those with impassable structures, are left
+    /// as they are.
+    pub fn with_creeps(
+        base: &LocalCostMatrix,
+        room: RoomName,
+        creeps: &[Position],
+        cost: u8,
+    ) -> LocalCostMatrix {
+        let mut matrix = base.clone();
+        for pos in creeps.iter().filter(|pos| pos.room_name() == room) {
+            let xy = pos.xy();
+            matrix[xy] = matrix[xy].max(cost);
+        }
+        matrix
+    }
+
     pub const fn get_bits(&self) -> &[u8; ROOM_AREA] {
         &self.bits
     }
@@ -67,6 +137,99 @@ impl LocalCostMatrix {
             .enumerate()
             .map(|(idx, val)| (linear_index_to_xy(idx), val))
     }
+
+    /// Creates a `LocalCostMatrix` from the serialized form of a matrix
+    /// produced by [`CostMatrix::serialize`], such as one stored in memory.
+    pub fn from_js_serialized(value: &JsValue) -> Result<Self, SerializedCostMatrixError> {
+        let packed: Vec<u32> = serde_wasm_bindgen::from_value(value.clone())
+            .map_err(|_| SerializedCostMatrixError::InvalidValue)?;
+
+        Self::from_serialized(&packed)
+    }
+
+    /// Gets the serialized form of this matrix, in the same format as
+    /// [`CostMatrix::serialize`], which can be loaded with
+    /// [`CostMatrix::deserialize`].
+    pub fn to_js_serialized(&self) -> Array {
+        self.to_serialized()
+            .into_iter()
+            .map(JsValue::from)
+            .collect()
+    }
+
+    /// Creates a `LocalCostMatrix` from the integers of a matrix serialized by
+    /// [`CostMatrix::serialize`].
+    ///
+    /// The engine serializes a matrix by viewing its costs as an array of
+    /// 625 unsigned 32-bit integers, so each integer holds the costs of four
+    /// consecutive tiles, in little-endian order.
+    pub fn from_serialized(packed: &[u32]) -> Result<Self, SerializedCostMatrixError> {
+        if packed.len() != SERIALIZED_LEN {
+            return Err(SerializedCostMatrixError::InvalidLength(packed.len()));
+        }
+
+        let mut bits = [0; ROOM_AREA];
+        for (chunk, value) in bits.chunks_exact_mut(4).zip(packed) {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+
+        Ok(LocalCostMatrix { bits })
+    }
+
+    /// Gets the integers of the serialized form of this matrix, in the same
+    /// format as [`CostMatrix::serialize`].
+    pub fn to_serialized(&self) -> Vec<u32> {
+        self.bits
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect()
+    }
+
+    /// Combines the costs from `other` into this matrix, tile by tile, using
+    /// the given [`MergeStrategy`].
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::local::{LocalCostMatrix, MergeStrategy, RoomXY};
+    ///
+    /// let xy = RoomXY::try_from((10, 10)).unwrap();
+    ///
+    /// let mut terrain = LocalCostMatrix::new();
+    /// terrain.set(xy, 10);
+    /// let mut avoid = LocalCostMatrix::new();
+    /// avoid.set(xy, 20);
+    ///
+    /// terrain.merge_from(&avoid, MergeStrategy::Add);
+    /// assert_eq!(terrain.get(xy), 30);
+    /// ```
+    pub fn merge_from(&mut self, other: &LocalCostMatrix, strategy: MergeStrategy) {
+        let merge = match strategy {
+            MergeStrategy::Max => |current: u8, other: u8| current.max(other),
+            MergeStrategy::Add => |current: u8, other: u8| current.saturating_add(other),
+            MergeStrategy::Overwrite => |current: u8, other: u8| match other {
+                0 => current,
+                _ => other,
+            },
+        };
+
+        for (current, &other) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *current = merge(*current, other);
+        }
+    }
+}
+
+/// How costs are combined by [`LocalCostMatrix::merge_from`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MergeStrategy {
+    /// Keep the higher of the two costs, so tiles blocked (cost 255) in
+    /// either matrix stay blocked.
+    Max,
+    /// Add the two costs together, saturating at 255.
+    Add,
+    /// Take the cost from the other matrix wherever it's nonzero, keeping the
+    /// existing cost elsewhere.
+    Overwrite,
 }
 
 impl From<LocalCostMatrix> for Vec<u8> {
@@ -167,3 +330,136 @@ mod serde_impls {
         Ok(bits_slice.try_into().unwrap())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{LocalCostMatrix, MergeStrategy, SerializedCostMatrixError};
+    use crate::local::{Position, RoomName, RoomXY};
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::try_from((x, y)).unwrap()
+    }
+
+    // returns a pair of matrices, with the costs at (0, 0) through (3, 0) of
+    // (0, 7), (5, 0), (5, 7), and (255, 1) respectively
+    fn sample_matrices() -> (LocalCostMatrix, LocalCostMatrix) {
+        let mut base = LocalCostMatrix::new();
+        let mut other = LocalCostMatrix::new();
+        other.set(xy(0, 0), 7);
+        base.set(xy(1, 0), 5);
+        base.set(xy(2, 0), 5);
+        other.set(xy(2, 0), 7);
+        base.set(xy(3, 0), 255);
+        other.set(xy(3, 0), 1);
+        (base, other)
+    }
+
+    fn first_row(matrix: &LocalCostMatrix) -> [u8; 4] {
+        [0, 1, 2, 3].map(|x| matrix.get(xy(x, 0)))
+    }
+
+    #[test]
+    fn set_many_and_clear() {
+        let mut matrix = LocalCostMatrix::new();
+        matrix.set(xy(49, 49), 3);
+
+        matrix.clear();
+        matrix.set_many(&[
+            (xy(1, 0), 10),
+            (xy(2, 0), 20),
+            (xy(1, 0), 30),
+            (xy(3, 0), 255),
+            (xy(3, 0), 0),
+        ]);
+
+        assert_eq!(first_row(&matrix), [0, 30, 20, 0]);
+        assert_eq!(matrix.get(xy(49, 49)), 0);
+
+        matrix.clear();
+        assert_eq!(matrix, LocalCostMatrix::new());
+    }
+
+    #[test]
+    fn serialized_round_trip() {
+        let mut matrix = LocalCostMatrix::new();
+        matrix.set(xy(0, 0), 1);
+        matrix.set(xy(0, 1), 2);
+        matrix.set(xy(0, 3), 255);
+        matrix.set(xy(49, 49), 7);
+
+        let packed = matrix.to_serialized();
+        assert_eq!(packed.len(), 625);
+        // the four tiles from (0, 0) to (0, 3) are packed into the first integer
+        assert_eq!(packed[0], 0xFF_00_02_01);
+        assert_eq!(packed[624], 0x07_00_00_00);
+        assert!(packed[1..624].iter().all(|&v| v == 0));
+
+        assert_eq!(LocalCostMatrix::from_serialized(&packed), Ok(matrix));
+    }
+
+    #[test]
+    fn serialized_wrong_length() {
+        assert_eq!(
+            LocalCostMatrix::from_serialized(&[0; 2500]),
+            Err(SerializedCostMatrixError::InvalidLength(2500))
+        );
+    }
+
+    #[test]
+    fn merge_max() {
+        let (mut base, other) = sample_matrices();
+        base.merge_from(&other, MergeStrategy::Max);
+        assert_eq!(first_row(&base), [7, 5, 7, 255]);
+    }
+
+    #[test]
+    fn merge_add_saturates() {
+        let (mut base, other) = sample_matrices();
+        base.merge_from(&other, MergeStrategy::Add);
+        assert_eq!(first_row(&base), [7, 5, 12, 255]);
+
+        let mut base = LocalCostMatrix::new();
+        base.set(xy(4, 0), 200);
+        let mut other = LocalCostMatrix::new();
+        other.set(xy(4, 0), 100);
+        base.merge_from(&other, MergeStrategy::Add);
+        assert_eq!(base.get(xy(4, 0)), 255);
+    }
+
+    #[test]
+    fn merge_overwrite() {
+        let (mut base, other) = sample_matrices();
+        base.merge_from(&other, MergeStrategy::Overwrite);
+        assert_eq!(first_row(&base), [7, 5, 7, 1]);
+    }
+
+    #[test]
+    fn matrix_with_creeps() {
+        let room: RoomName = "W1N1".parse().unwrap();
+        let other_room: RoomName = "W2N1".parse().unwrap();
+        let pos = |x, y, room| Position::from_room_xy(room, xy(x, y));
+
+        let mut base = LocalCostMatrix::new();
+        base.set(xy(10, 10), 255);
+        base.set(xy(11, 10), 1);
+
+        let creeps = [
+            pos(10, 10, room),
+            pos(11, 10, room),
+            pos(12, 10, room),
+            pos(13, 10, other_room),
+        ];
+        let matrix = LocalCostMatrix::with_creeps(&base, room, &creeps, 20);
+
+        // the wall stays impassable, and the other creeps' tiles are raised
+        assert_eq!(matrix.get(xy(10, 10)), 255);
+        assert_eq!(matrix.get(xy(11, 10)), 20);
+        assert_eq!(matrix.get(xy(12, 10)), 20);
+        // the creep in the other room doesn't affect the same tile here
+        assert_eq!(matrix.get(xy(13, 10)), 0);
+        assert_eq!(matrix.iter().filter(|&(_, cost)| cost > 0).count(), 3);
+
+        // the base matrix is unchanged
+        assert_eq!(base.get(xy(12, 10)), 0);
+    }
+}
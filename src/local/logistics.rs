@@ -0,0 +1,117 @@
+//! Helpers for planning the movement of resources between stores.
+use std::collections::HashMap;
+
+use crate::constants::ResourceType;
+
+/// Computes the transfers from storage to terminal needed to top the terminal
+/// up to `terminal_target` of each resource, given the contents of both.
+///
+/// Each entry is a resource type and the amount of it to move from storage to
+/// the terminal; the amount is limited by how much is available in storage.
+/// Resources the terminal already holds at least `terminal_target` of are
+/// left out, as are resources with none in storage. The terminal's free
+/// capacity isn't taken into account.
+///
+/// Entries are in the order of the engine's `RESOURCES_ALL` constant, as
+/// iterated by [`enum_iterator::all`].
+pub fn rebalance_plan(
+    storage: &HashMap<ResourceType, u32>,
+    terminal: &HashMap<ResourceType, u32>,
+    terminal_target: u32,
+) -> Vec<(ResourceType, u32)> {
+    enum_iterator::all::<ResourceType>()
+        .filter_map(|ty| {
+            let in_storage = storage.get(&ty).copied().unwrap_or(0);
+            let in_terminal = terminal.get(&ty).copied().unwrap_or(0);
+            let amount = terminal_target.saturating_sub(in_terminal).min(in_storage);
+            (amount > 0).then_some((ty, amount))
+        })
+        .collect()
+}
+
+/// Computes the change in the amount of each resource between two snapshots of
+/// a store, such as those taken with [`Store::to_hash_map`] on consecutive
+/// ticks.
+///
+/// Positive deltas are resources which were added to the store, and negative
+/// deltas are resources which were removed from it; resources with an
+/// unchanged amount are left out.
+///
+/// [`Store::to_hash_map`]: crate::objects::Store::to_hash_map
+pub fn store_diff(
+    before: &HashMap<ResourceType, u32>,
+    after: &HashMap<ResourceType, u32>,
+) -> HashMap<ResourceType, i64> {
+    before
+        .keys()
+        .chain(after.keys().filter(|ty| !before.contains_key(ty)))
+        .filter_map(|&ty| {
+            let amount_before = before.get(&ty).copied().unwrap_or(0);
+            let amount_after = after.get(&ty).copied().unwrap_or(0);
+            let delta = i64::from(amount_after) - i64::from(amount_before);
+            (delta != 0).then_some((ty, delta))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{rebalance_plan, store_diff};
+    use crate::constants::ResourceType;
+
+    #[test]
+    fn tops_up_terminal_from_storage() {
+        let storage = HashMap::from([
+            (ResourceType::Energy, 300_000),
+            (ResourceType::Hydrogen, 1_500),
+            (ResourceType::Oxygen, 20_000),
+            (ResourceType::Battery, 4_000),
+        ]);
+        let terminal = HashMap::from([
+            (ResourceType::Energy, 2_000),
+            (ResourceType::Hydrogen, 1_000),
+            (ResourceType::Oxygen, 7_000),
+            (ResourceType::Keanium, 500),
+        ]);
+
+        assert_eq!(
+            rebalance_plan(&storage, &terminal, 5_000),
+            vec![
+                // short 3k, plenty in storage
+                (ResourceType::Energy, 3_000),
+                // short 4k, but only 1.5k in storage
+                (ResourceType::Hydrogen, 1_500),
+                // not in the terminal at all
+                (ResourceType::Battery, 4_000),
+            ]
+        );
+    }
+
+    #[test]
+    fn pickup_and_drop_diff() {
+        // a creep drops its hydrogen, picks up some energy and all of a pile
+        // of oxygen, and keeps its utrium
+        let before = HashMap::from([
+            (ResourceType::Energy, 50),
+            (ResourceType::Hydrogen, 100),
+            (ResourceType::Utrium, 25),
+        ]);
+        let after = HashMap::from([
+            (ResourceType::Energy, 150),
+            (ResourceType::Oxygen, 40),
+            (ResourceType::Utrium, 25),
+        ]);
+
+        assert_eq!(
+            store_diff(&before, &after),
+            HashMap::from([
+                (ResourceType::Energy, 100),
+                (ResourceType::Hydrogen, -100),
+                (ResourceType::Oxygen, 40),
+            ])
+        );
+        assert!(store_diff(&after, &after).is_empty());
+    }
+}
@@ -0,0 +1,303 @@
+//! Minimum vertex cut between protected tiles and room exits, used to find
+//! candidate wall and rampart placements.
+use std::collections::VecDeque;
+
+use crate::constants::{Direction, Terrain};
+
+use super::{terrain_index_to_xy, xy_to_terrain_index, LocalRoomTerrain, RoomXY, ROOM_AREA};
+
+/// Capacity used for edges which must never be part of the cut.
+const INFINITE_CAPACITY: u32 = u32::MAX / 2;
+
+/// Finds a minimal set of tiles which, if blocked with walls or ramparts,
+/// separates every tile in `protect` from all of the room's exits.
+///
+/// This is a simplified min-cut: every buildable, non-wall tile is given a
+/// capacity of 1, movement is allowed between all 8 neighbors, and the
+/// maximum flow from the protected tiles to the exits is found. Tiles on the
+/// edge of the room and tiles within range 1 of an exit tile can't be built
+/// on, so they are never part of the returned cut; protected tiles which are
+/// within that range can't be separated from the exit and are ignored, as
+/// are protected tiles which are walls.
+///
+/// When multiple minimal cuts exist, the one closest to the protected tiles
+/// is returned. The result is in row-major order, and never includes natural
+/// walls, which already block movement.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{min_cut_perimeter, LocalRoomTerrain, RoomXY};
+///
+/// // an open room with no walls
+/// let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; 2500]));
+/// let center = unsafe { RoomXY::unchecked_new(25, 25) };
+///
+/// // the cheapest way to enclose a single tile is to surround it
+/// assert_eq!(min_cut_perimeter(&terrain, &[center]).len(), 8);
+/// ```
+#[doc(alias = "min_cut_walls")]
+pub fn min_cut_perimeter(terrain: &LocalRoomTerrain, protect: &[RoomXY]) -> Vec<RoomXY> {
+    let source = 2 * ROOM_AREA;
+    let sink = source + 1;
+    let mut graph = FlowGraph::new(sink + 1);
+
+    let mut exit_adjacent = [false; ROOM_AREA];
+    for idx in 0..ROOM_AREA {
+        let xy = terrain_index_to_xy(idx);
+        // corners are never exits, as with `RoomXY::edge`
+        if xy.edge().is_some() && terrain.get(xy) != Terrain::Wall {
+            exit_adjacent[idx] = true;
+            for neighbor in neighbors(xy) {
+                exit_adjacent[xy_to_terrain_index(neighbor)] = true;
+            }
+        }
+    }
+
+    let mut protected = [false; ROOM_AREA];
+    for &xy in protect {
+        let idx = xy_to_terrain_index(xy);
+        if !exit_adjacent[idx] && terrain.get(xy) != Terrain::Wall {
+            protected[idx] = true;
+        }
+    }
+
+    for idx in 0..ROOM_AREA {
+        let xy = terrain_index_to_xy(idx);
+        if terrain.get(xy) == Terrain::Wall {
+            continue;
+        }
+
+        // each tile is split into an 'in' node and an 'out' node, with the edge
+        // between them representing the cost of blocking the tile
+        let (tile_in, tile_out) = (2 * idx, 2 * idx + 1);
+        if protected[idx] {
+            graph.add_edge(source, tile_in, INFINITE_CAPACITY);
+            graph.add_edge(tile_in, tile_out, INFINITE_CAPACITY);
+        } else if exit_adjacent[idx] {
+            graph.add_edge(tile_in, tile_out, INFINITE_CAPACITY);
+            graph.add_edge(tile_out, sink, INFINITE_CAPACITY);
+        } else {
+            graph.add_edge(tile_in, tile_out, 1);
+        }
+
+        for neighbor in neighbors(xy) {
+            if terrain.get(neighbor) != Terrain::Wall {
+                graph.add_edge(
+                    tile_out,
+                    2 * xy_to_terrain_index(neighbor),
+                    INFINITE_CAPACITY,
+                );
+            }
+        }
+    }
+
+    graph.max_flow(source, sink);
+
+    let reachable = graph.reachable_from(source);
+    (0..ROOM_AREA)
+        .filter(|&idx| reachable[2 * idx] && !reachable[2 * idx + 1])
+        .map(terrain_index_to_xy)
+        .collect()
+}
+
+fn neighbors(xy: RoomXY) -> impl Iterator<Item = RoomXY> {
+    enum_iterator::all::<Direction>().filter_map(move |dir| xy.checked_add_direction(dir))
+}
+
+#[derive(Clone, Copy)]
+struct FlowEdge {
+    to: usize,
+    capacity: u32,
+}
+
+/// A flow network solved with Dinic's algorithm.
+struct FlowGraph {
+    // edges are stored in pairs, so the reverse of edge `i` is edge `i ^ 1`
+    edges: Vec<FlowEdge>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl FlowGraph {
+    fn new(node_count: usize) -> Self {
+        FlowGraph {
+            edges: Vec::new(),
+            adjacency: vec![Vec::new(); node_count],
+        }
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, capacity: u32) {
+        self.adjacency[from].push(self.edges.len());
+        self.edges.push(FlowEdge { to, capacity });
+        self.adjacency[to].push(self.edges.len());
+        self.edges.push(FlowEdge {
+            to: from,
+            capacity: 0,
+        });
+    }
+
+    fn levels(&self, source: usize) -> Vec<Option<u32>> {
+        let mut levels = vec![None; self.adjacency.len()];
+        let mut queue = VecDeque::new();
+        levels[source] = Some(0);
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            let level = levels[node].map(|l| l + 1);
+            for &edge in &self.adjacency[node] {
+                let FlowEdge { to, capacity } = self.edges[edge];
+                if capacity > 0 && levels[to].is_none() {
+                    levels[to] = level;
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        levels
+    }
+
+    fn reachable_from(&self, source: usize) -> Vec<bool> {
+        self.levels(source).iter().map(Option::is_some).collect()
+    }
+
+    fn max_flow(&mut self, source: usize, sink: usize) -> u32 {
+        let mut total = 0;
+
+        loop {
+            let levels = self.levels(source);
+            if levels[sink].is_none() {
+                return total;
+            }
+
+            let mut next_edge = vec![0; self.adjacency.len()];
+            loop {
+                let pushed = self.augment(source, sink, INFINITE_CAPACITY, &levels, &mut next_edge);
+                if pushed == 0 {
+                    break;
+                }
+                total = total.saturating_add(pushed);
+            }
+        }
+    }
+
+    fn augment(
+        &mut self,
+        node: usize,
+        sink: usize,
+        limit: u32,
+        levels: &[Option<u32>],
+        next_edge: &mut [usize],
+    ) -> u32 {
+        if node == sink {
+            return limit;
+        }
+
+        while next_edge[node] < self.adjacency[node].len() {
+            let edge = self.adjacency[node][next_edge[node]];
+            let FlowEdge { to, capacity } = self.edges[edge];
+
+            if capacity > 0 && levels[to] == levels[node].map(|l| l + 1) {
+                let pushed = self.augment(to, sink, limit.min(capacity), levels, next_edge);
+                if pushed > 0 {
+                    self.edges[edge].capacity -= pushed;
+                    self.edges[edge ^ 1].capacity += pushed;
+                    return pushed;
+                }
+            }
+
+            next_edge[node] += 1;
+        }
+
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::min_cut_perimeter;
+    use crate::local::{xy_to_terrain_index, LocalRoomTerrain, RoomXY, ROOM_AREA};
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::try_from((x, y)).unwrap()
+    }
+
+    #[test]
+    fn open_room_surrounds_single_tile() {
+        let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; ROOM_AREA]));
+
+        let cut = min_cut_perimeter(&terrain, &[xy(25, 25)]);
+
+        let expected: Vec<RoomXY> = [
+            (24, 24),
+            (25, 24),
+            (26, 24),
+            (24, 25),
+            (26, 25),
+            (24, 26),
+            (25, 26),
+            (26, 26),
+        ]
+        .iter()
+        .map(|&(x, y)| xy(x, y))
+        .collect();
+        assert_eq!(cut, expected);
+    }
+
+    #[test]
+    fn natural_walls_complete_the_cut() {
+        // a wall running the full height of the room along x = 26, right next
+        // to the protected tile
+        let mut bits = Box::new([0; ROOM_AREA]);
+        for y in 0..50 {
+            bits[xy_to_terrain_index(xy(26, y))] = 1;
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+
+        let cut = min_cut_perimeter(&terrain, &[xy(25, 25)]);
+
+        assert_eq!(
+            cut,
+            vec![xy(24, 24), xy(25, 24), xy(24, 25), xy(24, 26), xy(25, 26)]
+        );
+    }
+
+    #[test]
+    fn corridor_chokepoint() {
+        // all walls, except an open area at 10..=20 connected to the left
+        // exit by a corridor one tile wide along y = 15
+        let mut bits = Box::new([1; ROOM_AREA]);
+        for x in 10..=20 {
+            for y in 10..=20 {
+                bits[xy_to_terrain_index(xy(x, y))] = 0;
+            }
+        }
+        for x in 0..10 {
+            bits[xy_to_terrain_index(xy(x, 15))] = 0;
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+
+        let cut = min_cut_perimeter(&terrain, &[xy(15, 15), xy(12, 18)]);
+
+        assert_eq!(cut, vec![xy(9, 15)]);
+    }
+
+    #[test]
+    fn corners_are_not_exits() {
+        // all walls, except a diagonal line of tiles out to the open top left
+        // corner, which doesn't lead out of the room
+        let mut bits = Box::new([1; ROOM_AREA]);
+        for i in 0..=3 {
+            bits[xy_to_terrain_index(xy(i, i))] = 0;
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+
+        assert!(min_cut_perimeter(&terrain, &[xy(3, 3)]).is_empty());
+    }
+
+    #[test]
+    fn ignores_unprotectable_tiles() {
+        let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; ROOM_AREA]));
+
+        assert!(min_cut_perimeter(&terrain, &[xy(0, 10), xy(1, 10)]).is_empty());
+    }
+}
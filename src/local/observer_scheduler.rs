@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::RoomName;
+
+/// Tracks rooms requested from [`StructureObserver::observe_room`], so that
+/// the results can be found on the following tick when vision of the room is
+/// granted.
+///
+/// The scheduler is stored in Rust memory, and implements
+/// [`Serialize`]/[`Deserialize`] so that it can be persisted in `Memory` or
+/// `RawMemory` across global resets.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{ObserverScheduler, RoomName};
+///
+/// let target: RoomName = "W10N10".parse().unwrap();
+///
+/// let mut scheduler = ObserverScheduler::new();
+/// // after a successful `observer.observe_room(target)` on tick 100
+/// scheduler.record_request(target, 100);
+///
+/// // vision isn't granted until the next tick
+/// assert_eq!(scheduler.observed_rooms(100).count(), 0);
+/// assert_eq!(scheduler.observed_rooms(101).collect::<Vec<_>>(), vec![target]);
+/// ```
+///
+/// [`StructureObserver::observe_room`]: crate::objects::StructureObserver::observe_room
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ObserverScheduler {
+    /// Rooms requested, keyed by the game tick the request was made on.
+    requests: BTreeMap<u32, Vec<RoomName>>,
+}
+
+impl ObserverScheduler {
+    /// Create a new, empty `ObserverScheduler`.
+    #[inline]
+    pub const fn new() -> Self {
+        ObserverScheduler {
+            requests: BTreeMap::new(),
+        }
+    }
+
+    /// Record that a room was successfully requested for observation on the
+    /// given game tick.
+    ///
+    /// Requests from ticks more than one tick before `tick` are discarded, as
+    /// vision from them is no longer available.
+    pub fn record_request(&mut self, room_name: RoomName, tick: u32) {
+        self.prune(tick);
+
+        let rooms = self.requests.entry(tick).or_default();
+        if !rooms.contains(&room_name) {
+            rooms.push(room_name);
+        }
+    }
+
+    /// Rooms which were requested for observation on the given tick, and
+    /// which will be visible on the following tick.
+    pub fn pending_rooms(&self, tick: u32) -> impl Iterator<Item = RoomName> + '_ {
+        self.requests.get(&tick).into_iter().flatten().copied()
+    }
+
+    /// Rooms which were requested for observation on the previous tick, and
+    /// which are visible on the given tick.
+    pub fn observed_rooms(&self, tick: u32) -> impl Iterator<Item = RoomName> + '_ {
+        tick.checked_sub(1)
+            .and_then(|prev| self.requests.get(&prev))
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Whether the given room was requested for observation on the previous
+    /// tick, and is visible on the given tick.
+    pub fn is_observed(&self, room_name: RoomName, tick: u32) -> bool {
+        self.observed_rooms(tick).any(|r| r == room_name)
+    }
+
+    /// Discard all requests made before the previous tick, which are no
+    /// longer useful for determining visibility.
+    pub fn prune(&mut self, tick: u32) {
+        let keep_from = tick.saturating_sub(1);
+        self.requests = self.requests.split_off(&keep_from);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ObserverScheduler;
+    use crate::local::RoomName;
+
+    #[test]
+    fn request_visible_next_tick() {
+        let w1n1: RoomName = "W1N1".parse().unwrap();
+        let e5s5: RoomName = "E5S5".parse().unwrap();
+
+        let mut scheduler = ObserverScheduler::new();
+        scheduler.record_request(w1n1, 10);
+        scheduler.record_request(e5s5, 10);
+        // duplicate requests are only tracked once
+        scheduler.record_request(w1n1, 10);
+
+        assert_eq!(
+            scheduler.pending_rooms(10).collect::<Vec<_>>(),
+            vec![w1n1, e5s5]
+        );
+        assert_eq!(scheduler.observed_rooms(10).count(), 0);
+        assert!(!scheduler.is_observed(w1n1, 10));
+
+        assert_eq!(
+            scheduler.observed_rooms(11).collect::<Vec<_>>(),
+            vec![w1n1, e5s5]
+        );
+        assert!(scheduler.is_observed(w1n1, 11));
+        assert!(scheduler.is_observed(e5s5, 11));
+
+        // vision only lasts for the tick after the request
+        assert_eq!(scheduler.observed_rooms(12).count(), 0);
+
+        scheduler.record_request(e5s5, 12);
+        assert_eq!(scheduler.pending_rooms(10).count(), 0);
+        assert!(scheduler.is_observed(e5s5, 13));
+        assert!(!scheduler.is_observed(w1n1, 13));
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let mut scheduler = ObserverScheduler::new();
+        scheduler.record_request("W1N1".parse().unwrap(), 10);
+        scheduler.record_request("E5S5".parse().unwrap(), 11);
+
+        let serialized = serde_json::to_string(&scheduler).unwrap();
+        let parsed: ObserverScheduler = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(scheduler, parsed);
+    }
+}
@@ -258,6 +258,25 @@ impl Position {
         Self::from_coords_adjusted_and_room_packed(x.into(), y.into(), room_name.packed_repr())
     }
 
+    /// Create a new Position from a [`RoomXY`] coordinate pair in the given
+    /// room.
+    ///
+    /// This is the inverse of [`Position::xy`] combined with
+    /// [`Position::room_name`]:
+    ///
+    /// ```
+    /// use screeps::{Position, RoomXY};
+    ///
+    /// let xy = RoomXY::try_from((20, 21)).unwrap();
+    /// let pos = Position::from_room_xy("E5N6".parse().unwrap(), xy);
+    /// assert_eq!(pos.xy(), xy);
+    /// assert_eq!(Position::from_room_xy(pos.room_name(), pos.xy()), pos);
+    /// ```
+    #[inline]
+    pub fn from_room_xy(room_name: RoomName, xy: RoomXY) -> Self {
+        Self::new(xy.x, xy.y, room_name)
+    }
+
     /// Creates a `Position` from x,y coordinates and room coordinates
     /// already adjusted to be positive using `HALF_WORLD_SIZE`.
     ///
@@ -485,7 +504,7 @@ pub mod serde_position_packed {
 
 #[cfg(test)]
 mod test {
-    use super::{Position, RoomCoordinate};
+    use super::{Position, RoomCoordinate, RoomXY};
 
     fn gen_test_positions() -> Vec<(u32, (RoomCoordinate, RoomCoordinate, &'static str))> {
         unsafe {
@@ -581,4 +600,16 @@ mod test {
             assert_eq!(pos.packed_repr(), packed);
         }
     }
+
+    #[test]
+    fn room_xy_round_trip() {
+        // includes the top left room, which is `sim` when that feature is enabled
+        for (packed, (x, y, name)) in gen_test_positions().iter().copied() {
+            let pos = Position::from_packed(packed);
+            let xy = RoomXY { x, y };
+            assert_eq!(pos.xy(), xy);
+            assert_eq!(Position::from_room_xy(name.parse().unwrap(), xy), pos);
+            assert_eq!(Position::from_room_xy(pos.room_name(), pos.xy()), pos);
+        }
+    }
 }
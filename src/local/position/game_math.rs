@@ -123,6 +123,10 @@ impl Position {
 
     /// True if this position is in the same room as the target, and the range
     /// is at most 1.
+    ///
+    /// This matches `RoomPosition.isNearTo`: a position is near to itself, and
+    /// never near to a position in another room, even one which is adjacent
+    /// across a room border.
     #[inline]
     pub fn is_near_to(self, target: Position) -> bool {
         self.room_name() == target.room_name()
@@ -143,4 +147,42 @@ mod test {
         let b = Position::new(two, two, RoomName::from_coords(1, 1).unwrap());
         assert_eq!(a.get_direction_to(b), Some(Direction::BottomRight));
     }
+
+    #[test]
+    fn test_is_near_to() {
+        let room = RoomName::from_coords(1, 1).unwrap();
+        let pos = |x, y| {
+            Position::new(
+                RoomCoordinate::new(x).unwrap(),
+                RoomCoordinate::new(y).unwrap(),
+                room,
+            )
+        };
+
+        // same tile, orthogonally adjacent, and diagonally adjacent
+        assert!(pos(10, 10).is_near_to(pos(10, 10)));
+        assert!(pos(10, 10).is_near_to(pos(10, 11)));
+        assert!(pos(10, 10).is_near_to(pos(9, 9)));
+        // two tiles away
+        assert!(!pos(10, 10).is_near_to(pos(12, 10)));
+        assert!(!pos(10, 10).is_near_to(pos(11, 12)));
+
+        // the same coordinates in another room
+        let other_room = Position::new(
+            RoomCoordinate::new(10).unwrap(),
+            RoomCoordinate::new(10).unwrap(),
+            RoomName::from_coords(2, 1).unwrap(),
+        );
+        assert!(!pos(10, 10).is_near_to(other_room));
+
+        // adjacent in world coordinates, but across a room border
+        let west_edge = pos(0, 10);
+        let east_edge_of_neighbor = Position::new(
+            RoomCoordinate::new(49).unwrap(),
+            RoomCoordinate::new(10).unwrap(),
+            RoomName::from_coords(0, 1).unwrap(),
+        );
+        assert_eq!(west_edge.get_range_to(east_edge_of_neighbor), 1);
+        assert!(!west_edge.is_near_to(east_edge_of_neighbor));
+    }
 }
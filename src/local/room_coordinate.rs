@@ -2,10 +2,16 @@ use std::{convert::TryFrom, error::Error, fmt};
 
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::constants::{Direction, ROOM_SIZE};
+use crate::constants::{Direction, ExitDirection, ROOM_SIZE};
 
 pub(crate) const ROOM_AREA: usize = (ROOM_SIZE as usize) * (ROOM_SIZE as usize);
 
+/// The tile at the center of a room, (25, 25).
+pub const ROOM_CENTER: RoomXY = RoomXY {
+    x: RoomCoordinate::new_const::<{ ROOM_SIZE / 2 }>(),
+    y: RoomCoordinate::new_const::<{ ROOM_SIZE / 2 }>(),
+};
+
 #[derive(Debug, Clone, Copy)]
 pub struct OutOfBoundsError(u8);
 
@@ -53,6 +59,20 @@ pub const fn xy_to_terrain_index(xy: RoomXY) -> usize {
     xy.y.u8() as usize * ROOM_SIZE as usize + xy.x.u8() as usize
 }
 
+/// Converts a [`RoomXY`] coordinate pair to a row-major index into an array
+/// with `stride` elements per row, for use with arrays covering part of a
+/// room or with wider rows than the room itself.
+///
+/// With a stride of [`ROOM_SIZE`] this is the same as
+/// [`xy_to_terrain_index`]. Coordinates with an `x` of `stride` or greater
+/// give indices which overlap the next row.
+///
+/// [`ROOM_SIZE`]: crate::constants::ROOM_SIZE
+#[inline]
+pub const fn xy_to_index_with_stride(xy: RoomXY, stride: usize) -> usize {
+    xy.y.u8() as usize * stride + xy.x.u8() as usize
+}
+
 /// Converts a terrain index from the internal representation of a
 /// [`RoomTerrain`] or [`LocalRoomTerrain`] to a [`RoomXY`] coordinate pair for
 /// the position the index represents.
@@ -89,6 +109,37 @@ impl RoomCoordinate {
         }
     }
 
+    /// Create a `RoomCoordinate` from a constant `u8`, checking at compile
+    /// time that the coordinate is in the valid room size range.
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::local::RoomCoordinate;
+    ///
+    /// const EDGE: RoomCoordinate = RoomCoordinate::new_const::<49>();
+    ///
+    /// assert_eq!(EDGE.u8(), 49);
+    /// ```
+    ///
+    /// Coordinates of `ROOM_SIZE` or greater fail to compile:
+    ///
+    /// ```compile_fail
+    /// use screeps::local::RoomCoordinate;
+    ///
+    /// const OUTSIDE: RoomCoordinate = RoomCoordinate::new_const::<50>();
+    /// ```
+    #[inline]
+    pub const fn new_const<const C: u8>() -> Self {
+        struct Check<const C: u8>;
+        impl<const C: u8> Check<C> {
+            const IN_BOUNDS: () = assert!(C < ROOM_SIZE, "Out of bounds coordinate");
+        }
+
+        let () = Check::<C>::IN_BOUNDS;
+        RoomCoordinate(C)
+    }
+
     /// Create a `RoomCoordinate` from a `u8`, without checking whether it's in
     /// the range of valid values.
     ///
@@ -109,6 +160,11 @@ impl RoomCoordinate {
         self.0
     }
 
+    /// Whether this coordinate is on the edge of the room, either 0 or 49.
+    pub const fn is_room_edge(self) -> bool {
+        self.0 == 0 || self.0 == ROOM_SIZE - 1
+    }
+
     /// Get the coordinate adjusted by a certain value, returning `None` if the
     /// result is outside the valid range.
     ///
@@ -167,6 +223,31 @@ impl RoomCoordinate {
         // SAFETY: we've ensured that this coord is in the valid range
         unsafe { RoomCoordinate::unchecked_new(result) }
     }
+
+    /// Get an iterator over the coordinates from `start` to `end`, inclusive.
+    /// The iterator is empty if `start` is greater than `end`.
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::local::RoomCoordinate;
+    ///
+    /// let start = RoomCoordinate::new(10).unwrap();
+    /// let end = RoomCoordinate::new(13).unwrap();
+    ///
+    /// assert_eq!(
+    ///     RoomCoordinate::range(start, end).map(u8::from).collect::<Vec<_>>(),
+    ///     vec![10, 11, 12, 13]
+    /// );
+    /// assert_eq!(RoomCoordinate::range(end, start).count(), 0);
+    /// ```
+    pub fn range(
+        start: RoomCoordinate,
+        end: RoomCoordinate,
+    ) -> impl DoubleEndedIterator<Item = RoomCoordinate> + ExactSizeIterator {
+        // SAFETY: every value between two valid coordinates is also valid
+        (start.0..=end.0).map(|c| unsafe { RoomCoordinate::unchecked_new(c) })
+    }
 }
 
 impl fmt::Display for RoomCoordinate {
@@ -294,6 +375,127 @@ impl RoomXY {
         let (dx, dy) = rhs.into();
         self.saturating_add((dx as i8, dy as i8))
     }
+
+    /// Checks whether this position is at most one tile away from another,
+    /// including diagonally.
+    ///
+    /// Like [`Position::is_near_to`], a position counts as adjacent to itself.
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::local::RoomXY;
+    ///
+    /// let one = unsafe { RoomXY::unchecked_new(1, 1) };
+    /// let two = unsafe { RoomXY::unchecked_new(2, 2) };
+    /// let three = unsafe { RoomXY::unchecked_new(3, 3) };
+    ///
+    /// assert!(one.is_adjacent_to(two));
+    /// assert!(one.is_adjacent_to(one));
+    /// assert!(!one.is_adjacent_to(three));
+    /// ```
+    ///
+    /// [`Position::is_near_to`]: crate::local::Position::is_near_to
+    pub fn is_adjacent_to(self, other: RoomXY) -> bool {
+        self.x.u8().abs_diff(other.x.u8()) <= 1 && self.y.u8().abs_diff(other.y.u8()) <= 1
+    }
+
+    /// Whether this position is on the boundary of the room, where exit tiles
+    /// can be. This includes the corners.
+    pub const fn is_room_edge(self) -> bool {
+        self.x.is_room_edge() || self.y.is_room_edge()
+    }
+
+    /// Whether this position is one of the four corners of the room.
+    pub const fn is_room_corner(self) -> bool {
+        self.x.is_room_edge() && self.y.is_room_edge()
+    }
+
+    /// Get the edge of the room this position is on, given as the direction of
+    /// the exits along that edge.
+    ///
+    /// Returns `None` for positions which aren't on the edge of the room, and
+    /// for the corners, which are never exits since they border two other
+    /// rooms.
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::{constants::ExitDirection, local::RoomXY};
+    ///
+    /// let top = unsafe { RoomXY::unchecked_new(20, 0) };
+    /// let corner = unsafe { RoomXY::unchecked_new(49, 0) };
+    /// let interior = unsafe { RoomXY::unchecked_new(20, 1) };
+    ///
+    /// assert_eq!(top.edge(), Some(ExitDirection::Top));
+    /// assert_eq!(corner.edge(), None);
+    /// assert!(corner.is_room_corner());
+    /// assert_eq!(interior.edge(), None);
+    /// ```
+    pub const fn edge(self) -> Option<ExitDirection> {
+        const MAX: u8 = ROOM_SIZE - 1;
+        match (self.x.0, self.y.0) {
+            (0 | MAX, 0 | MAX) => None,
+            (_, 0) => Some(ExitDirection::Top),
+            (MAX, _) => Some(ExitDirection::Right),
+            (_, MAX) => Some(ExitDirection::Bottom),
+            (0, _) => Some(ExitDirection::Left),
+            _ => None,
+        }
+    }
+
+    /// Get an iterator over every tile in the room, ordered in an outward
+    /// spiral from this position.
+    ///
+    /// This position comes first, followed by each ring of tiles at
+    /// increasing range. Each ring starts from the tile directly above the
+    /// center and goes clockwise, so the first ring is in the order of
+    /// [`Direction`] from [`Direction::Top`] to [`Direction::TopLeft`]. Tiles
+    /// outside of the room are skipped.
+    ///
+    /// Example usage:
+    ///
+    /// ```
+    /// use screeps::local::RoomXY;
+    ///
+    /// let xy = |x, y| RoomXY::try_from((x, y)).unwrap();
+    ///
+    /// let spiral: Vec<RoomXY> = xy(10, 10).spiral_from().take(5).collect();
+    /// assert_eq!(
+    ///     spiral,
+    ///     [xy(10, 10), xy(10, 9), xy(11, 9), xy(11, 10), xy(11, 11)]
+    /// );
+    /// ```
+    pub fn spiral_from(self) -> impl Iterator<Item = RoomXY> {
+        const MAX: u8 = ROOM_SIZE - 1;
+        let (x, y) = (self.x.u8(), self.y.u8());
+        let max_range = x.max(MAX - x).max(y).max(MAX - y) as i8;
+
+        (0..=max_range)
+            .flat_map(ring_offsets)
+            .filter_map(move |offset| self.checked_add(offset))
+    }
+}
+
+/// The offsets of the tiles at a range from a center tile, starting from
+/// directly above it and going clockwise.
+fn ring_offsets(range: i8) -> impl Iterator<Item = (i8, i8)> {
+    let r = range;
+    let top_right = (0..r).map(move |dx| (dx, -r));
+    let right = (-r..r).map(move |dy| (r, dy));
+    let bottom = (-r + 1..=r).rev().map(move |dx| (dx, r));
+    let left = (-r + 1..=r).rev().map(move |dy| (-r, dy));
+    let top_left = (-r..0).map(move |dx| (dx, -r));
+
+    // the center is the only tile at range 0
+    let center = (range == 0).then_some((0, 0));
+    center
+        .into_iter()
+        .chain(top_right)
+        .chain(right)
+        .chain(bottom)
+        .chain(left)
+        .chain(top_left)
 }
 
 impl fmt::Display for RoomXY {
@@ -397,3 +599,140 @@ impl<'de> Deserialize<'de> for RoomXY {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{
+        xy_to_index_with_stride, xy_to_terrain_index, RoomCoordinate, RoomXY, ROOM_CENTER,
+    };
+    use crate::constants::{ExitDirection, ROOM_SIZE};
+
+    #[test]
+    fn index_with_stride() {
+        for x in 0..ROOM_SIZE {
+            for y in 0..ROOM_SIZE {
+                let xy = RoomXY::try_from((x, y)).unwrap();
+                assert_eq!(
+                    xy_to_index_with_stride(xy, ROOM_SIZE as usize),
+                    xy_to_terrain_index(xy)
+                );
+            }
+        }
+
+        // a 10 wide sub-grid
+        let xy = RoomXY::try_from((3, 2)).unwrap();
+        assert_eq!(xy_to_index_with_stride(xy, 10), 23);
+    }
+
+    #[test]
+    fn adjacency() {
+        let xy = |x, y| RoomXY::try_from((x, y)).unwrap();
+
+        assert!(xy(10, 10).is_adjacent_to(xy(10, 10)));
+        assert!(xy(10, 10).is_adjacent_to(xy(11, 10)));
+        assert!(xy(10, 10).is_adjacent_to(xy(10, 9)));
+        assert!(xy(10, 10).is_adjacent_to(xy(9, 11)));
+        assert!(xy(10, 10).is_adjacent_to(xy(11, 11)));
+        assert!(!xy(10, 10).is_adjacent_to(xy(12, 10)));
+        assert!(!xy(10, 10).is_adjacent_to(xy(11, 8)));
+        assert!(!xy(0, 0).is_adjacent_to(xy(49, 49)));
+    }
+
+    #[test]
+    fn edges_and_corners() {
+        let xy = |x, y| RoomXY::try_from((x, y)).unwrap();
+
+        for corner in [xy(0, 0), xy(49, 0), xy(0, 49), xy(49, 49)] {
+            assert!(corner.is_room_edge(), "{corner}");
+            assert!(corner.is_room_corner(), "{corner}");
+            assert_eq!(corner.edge(), None, "{corner}");
+        }
+
+        let edges = [
+            (xy(25, 0), ExitDirection::Top),
+            (xy(49, 1), ExitDirection::Right),
+            (xy(48, 49), ExitDirection::Bottom),
+            (xy(0, 30), ExitDirection::Left),
+        ];
+        for (tile, edge) in edges {
+            assert!(tile.is_room_edge(), "{tile}");
+            assert!(!tile.is_room_corner(), "{tile}");
+            assert_eq!(tile.edge(), Some(edge), "{tile}");
+        }
+
+        for interior in [xy(1, 1), xy(25, 25), xy(48, 48), xy(1, 48)] {
+            assert!(!interior.is_room_edge(), "{interior}");
+            assert!(!interior.is_room_corner(), "{interior}");
+            assert_eq!(interior.edge(), None, "{interior}");
+        }
+    }
+
+    #[test]
+    fn const_coordinates() {
+        const ZERO: RoomCoordinate = RoomCoordinate::new_const::<0>();
+        const MAX: RoomCoordinate = RoomCoordinate::new_const::<{ ROOM_SIZE - 1 }>();
+
+        assert_eq!(ZERO, RoomCoordinate::new(0).unwrap());
+        assert_eq!(MAX, RoomCoordinate::new(49).unwrap());
+        assert_eq!(<(u8, u8)>::from(ROOM_CENTER), (25, 25));
+    }
+
+    #[test]
+    fn coordinate_ranges() {
+        let coord = |c| RoomCoordinate::new(c).unwrap();
+
+        let sub_range: Vec<u8> = RoomCoordinate::range(coord(47), coord(49))
+            .map(u8::from)
+            .collect();
+        assert_eq!(sub_range, vec![47, 48, 49]);
+
+        assert_eq!(RoomCoordinate::range(coord(7), coord(7)).len(), 1);
+
+        let full_range: Vec<u8> = RoomCoordinate::range(coord(0), coord(ROOM_SIZE - 1))
+            .map(u8::from)
+            .collect();
+        assert_eq!(full_range, (0..ROOM_SIZE).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn spiral_order() {
+        let xy = |x: u8, y: u8| RoomXY::try_from((x, y)).unwrap();
+
+        let spiral: Vec<RoomXY> = xy(25, 25).spiral_from().take(9).collect();
+        assert_eq!(
+            spiral,
+            vec![
+                xy(25, 25),
+                xy(25, 24),
+                xy(26, 24),
+                xy(26, 25),
+                xy(26, 26),
+                xy(25, 26),
+                xy(24, 26),
+                xy(24, 25),
+                xy(24, 24),
+            ]
+        );
+
+        // the second ring continues clockwise from directly above
+        let ring: Vec<RoomXY> = xy(25, 25).spiral_from().skip(9).take(3).collect();
+        assert_eq!(ring, vec![xy(25, 23), xy(26, 23), xy(27, 23)]);
+
+        // next to the top left corner, tiles off the top and left are dropped
+        let spiral: Vec<RoomXY> = xy(0, 1).spiral_from().take(6).collect();
+        assert_eq!(
+            spiral,
+            vec![xy(0, 1), xy(0, 0), xy(1, 0), xy(1, 1), xy(1, 2), xy(0, 2)]
+        );
+
+        // every tile in the room is visited exactly once
+        for center in [xy(0, 0), xy(49, 49), xy(3, 40), xy(25, 25)] {
+            let tiles: Vec<RoomXY> = center.spiral_from().collect();
+            let unique: HashSet<RoomXY> = tiles.iter().copied().collect();
+            assert_eq!(tiles.len(), 2500);
+            assert_eq!(unique.len(), 2500);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::ops::{Index, IndexMut};
+
+use super::{terrain_index_to_xy, xy_to_terrain_index, RoomXY, ROOM_AREA};
+
+/// A value for every tile in a room, stored in Rust memory and indexed by
+/// [`RoomXY`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoomDataArray<T> {
+    data: Box<[T; ROOM_AREA]>,
+}
+
+impl<T: Copy> RoomDataArray<T> {
+    /// Creates a `RoomDataArray` with every tile set to `value`.
+    pub fn new(value: T) -> Self {
+        RoomDataArray {
+            data: Box::new([value; ROOM_AREA]),
+        }
+    }
+}
+
+impl<T: Copy + Default> Default for RoomDataArray<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> RoomDataArray<T> {
+    /// Gets a reference to the value of a tile.
+    pub fn get(&self, xy: RoomXY) -> &T {
+        &self[xy]
+    }
+
+    /// Replaces the value of a tile.
+    pub fn set(&mut self, xy: RoomXY, value: T) {
+        self[xy] = value;
+    }
+
+    /// Iterates over the value of each tile, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (RoomXY, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(idx, val)| (terrain_index_to_xy(idx), val))
+    }
+
+    /// Iterates mutably over the value of each tile, in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (RoomXY, &mut T)> {
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(|(idx, val)| (terrain_index_to_xy(idx), val))
+    }
+}
+
+impl<T> Index<RoomXY> for RoomDataArray<T> {
+    type Output = T;
+
+    fn index(&self, xy: RoomXY) -> &Self::Output {
+        // SAFETY: RoomXY is always a valid coordinate.
+        unsafe { self.data.get_unchecked(xy_to_terrain_index(xy)) }
+    }
+}
+
+impl<T> IndexMut<RoomXY> for RoomDataArray<T> {
+    fn index_mut(&mut self, xy: RoomXY) -> &mut Self::Output {
+        // SAFETY: RoomXY is always a valid coordinate.
+        unsafe { self.data.get_unchecked_mut(xy_to_terrain_index(xy)) }
+    }
+}
@@ -31,6 +31,10 @@ use super::{HALF_WORLD_SIZE, VALID_ROOM_NAME_COORDINATES};
 /// This follows left-to-right reading order when looking at the Screeps map
 /// from above.
 ///
+/// The ordering is consistent with [`Eq`] and [`Hash`], which both operate on
+/// the packed representation: two `RoomName`s compare as equal exactly when
+/// they refer to the same room.
+///
 /// [`BTreeMap`]: std::collections::BTreeMap
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct RoomName {
@@ -144,6 +148,9 @@ impl RoomName {
     /// Gets the x coordinate.
     ///
     /// For `Wxx` rooms, returns `-xx - 1`. For `Exx` rooms, returns `xx`.
+    ///
+    /// If the `sim` feature is enabled, the `sim` room has the coordinates of
+    /// `W127N127`, -128.
     #[inline]
     pub const fn x_coord(&self) -> i32 {
         ((self.packed >> 8) & 0xFF) as i32 - HALF_WORLD_SIZE
@@ -152,30 +159,77 @@ impl RoomName {
     /// Gets the y coordinate.
     ///
     /// For `Nyy` rooms, returns `-yy - 1`. For `Syy` rooms, returns `yy`.
+    ///
+    /// If the `sim` feature is enabled, the `sim` room has the coordinates of
+    /// `W127N127`, -128.
     #[inline]
     pub const fn y_coord(&self) -> i32 {
         (self.packed & 0xFF) as i32 - HALF_WORLD_SIZE
     }
 
+    /// Gets the packed representation of this room name, the same integer the
+    /// engine uses for the room in the upper 16 bits of a packed position.
+    ///
+    /// The high byte is `room_x + 128` and the low byte is `room_y + 128`,
+    /// where `room_x` and `room_y` are the coordinates returned by the
+    /// engine's `roomNameToXY`, as in [`RoomName::x_coord`] and
+    /// [`RoomName::y_coord`]:
+    ///
+    /// - For `Wxx` rooms, `room_x = -xx - 1`. For `Exx` rooms, `room_x = xx`.
+    /// - For `Nyy` rooms, `room_y = -yy - 1`. For `Syy` rooms, `room_y = yy`.
+    ///
+    /// So `W127N127` is `0x0000`, `W0N0` is `0x7F7F`, `E0S0` is `0x8080`, and
+    /// `E127S127` is `0xFFFF`.
+    ///
+    /// If the `sim` feature is enabled, the `sim` room has the packed
+    /// representation of `W127N127`, 0.
     #[inline]
-    pub(super) const fn packed_repr(&self) -> u16 {
+    pub const fn packed_repr(&self) -> u16 {
         self.packed
     }
 
+    /// Creates a room name from the packed representation returned by
+    /// [`RoomName::packed_repr`].
+    ///
+    /// Every `u16` is a valid packed room name.
+    #[inline]
+    pub const fn from_packed_repr(packed: u16) -> Self {
+        Self::from_packed(packed)
+    }
+
     /// Adds an `(x, y)` pair to this room's name.
     ///
     /// # Errors
     /// Returns an error if the coordinates are outside of the valid room name
     /// bounds.
     ///
+    /// If the `sim` feature is enabled, the `sim` room has no neighbors:
+    /// offsetting it by `(0, 0)` returns the `sim` room, while any other offset
+    /// returns `None`, as does any offset which would reach the `sim` room
+    /// from another room.
+    ///
     /// For a panicking variant of this function, use the implementation of
     /// [`ops::Add`] for `(i32, i32)`.
     pub fn checked_add(&self, offset: (i32, i32)) -> Option<RoomName> {
+        if offset == (0, 0) {
+            return Some(*self);
+        }
         let (x1, y1) = (self.x_coord(), self.y_coord());
         let (x2, y2) = offset;
         let new_x = x1.checked_add(x2)?;
         let new_y = y1.checked_add(y2)?;
-        Self::from_coords(new_x, new_y).ok()
+        let room = Self::from_coords(new_x, new_y).ok()?;
+        if self.is_sim() || room.is_sim() {
+            return None;
+        }
+        Some(room)
+    }
+
+    /// Whether this is the `sim` room, which is only ever true if the `sim`
+    /// feature is enabled.
+    #[inline]
+    const fn is_sim(&self) -> bool {
+        cfg!(feature = "sim") && self.packed == 0
     }
 
     /// Converts this RoomName into an efficient, stack-based string.
@@ -292,10 +346,12 @@ impl ops::Add<(i32, i32)> for RoomName {
     ///
     /// # Panics
     ///
-    /// Will panic if the addition overflows the boundaries of RoomName.
+    /// Will panic if the addition overflows the boundaries of RoomName, or if
+    /// the `sim` feature is enabled and the offset leads to or from the `sim`
+    /// room; see [`RoomName::checked_add`].
     #[inline]
-    fn add(self, (x, y): (i32, i32)) -> Self {
-        RoomName::from_coords(self.x_coord() + x, self.y_coord() + y)
+    fn add(self, offset: (i32, i32)) -> Self {
+        self.checked_add(offset)
             .expect("expected addition to keep RoomName in-bounds")
     }
 }
@@ -309,11 +365,15 @@ impl ops::Sub<(i32, i32)> for RoomName {
     ///
     /// # Panics
     ///
-    /// Will panic if the subtraction overflows the boundaries of RoomName.
+    /// Will panic if the subtraction overflows the boundaries of RoomName, or
+    /// if the `sim` feature is enabled and the offset leads to or from the
+    /// `sim` room; see [`RoomName::checked_add`].
     #[inline]
     fn sub(self, (x, y): (i32, i32)) -> Self {
-        RoomName::from_coords(self.x_coord() - x, self.y_coord() - y)
-            .expect("expected addition to keep RoomName in-bounds")
+        x.checked_neg()
+            .zip(y.checked_neg())
+            .and_then(|offset| self.checked_add(offset))
+            .expect("expected subtraction to keep RoomName in-bounds")
     }
 }
 
@@ -574,7 +634,34 @@ mod serde {
 
 #[cfg(test)]
 mod test {
-    use crate::RoomName;
+    use crate::{
+        local::{Position, ROOM_CENTER},
+        RoomName,
+    };
+
+    #[test]
+    fn engine_packed_repr() {
+        let known_rooms = [
+            ("E0N0", 0x807F),
+            ("W0S0", 0x7F80),
+            ("W0N0", 0x7F7F),
+            ("E0S0", 0x8080),
+            ("E10N5", 0x8A7A),
+            ("W8S12", 0x778C),
+            ("E127S127", 0xFFFF),
+        ];
+
+        for (name, packed) in known_rooms {
+            let room: RoomName = name.parse().unwrap();
+            assert_eq!(room.packed_repr(), packed, "packing {name}");
+            assert_eq!(RoomName::from_packed_repr(packed), room, "unpacking {name}");
+        }
+
+        // consistent with the room part of the engine's packed positions
+        let room: RoomName = "W0N0".parse().unwrap();
+        let pos = Position::from_room_xy(room, ROOM_CENTER);
+        assert_eq!(pos.packed_repr() >> 16, u32::from(room.packed_repr()));
+    }
 
     #[test]
     fn test_string_equality() {
@@ -613,9 +700,13 @@ mod test {
         assert_eq!(w0n0.checked_add((1, 0)), Some(e0n0));
         assert_eq!(e0n0.checked_add((10, -75)), Some(e10n75));
         assert_eq!(e10n75.checked_add((-14, 22)), Some(w3n53));
-        assert_eq!(w3n53.checked_add((-124, -74)), Some(w127n127));
+        // with the `sim` feature, W127N127 is the sim room, which can't be
+        // reached from or offset to other rooms
+        if !cfg!(feature = "sim") {
+            assert_eq!(w3n53.checked_add((-124, -74)), Some(w127n127));
+            assert_eq!(w127n127.checked_add((127, 127)), Some(w0n0));
+        }
 
-        assert_eq!(w127n127.checked_add((127, 127)), Some(w0n0));
         assert_eq!(w127s127.checked_add((127, -128)), Some(w0n0));
         assert_eq!(e127n127.checked_add((-128, 127)), Some(w0n0));
         assert_eq!(e127s127.checked_add((-128, -128)), Some(w0n0));
@@ -647,4 +738,77 @@ mod test {
         assert_eq!(w127n5.checked_add((i32::MIN, 0)), None);
         assert_eq!(w127n5.checked_add((i32::MIN, i32::MAX)), None);
     }
+
+    #[test]
+    fn sorting_mixed_hemispheres() {
+        let mut room_names: Vec<RoomName> = [
+            "E0S0", "W0N0", "E5N5", "W5S5", "E0N0", "W0S0", "W5N5", "E5S5", "E127S127", "W127N127",
+            "W1N0", "E1N0",
+        ]
+        .iter()
+        .map(|name| name.parse().unwrap())
+        .collect();
+
+        room_names.sort();
+
+        let sorted: Vec<String> = room_names.iter().map(ToString::to_string).collect();
+        let top_left_room = if cfg!(feature = "sim") {
+            "sim"
+        } else {
+            "W127N127"
+        };
+        assert_eq!(
+            sorted,
+            [
+                top_left_room,
+                "W5N5",
+                "E5N5",
+                "W1N0",
+                "W0N0",
+                "E0N0",
+                "E1N0",
+                "W0S0",
+                "E0S0",
+                "W5S5",
+                "E5S5",
+                "E127S127",
+            ]
+        );
+
+        // ordering agrees with equality
+        for a in &room_names {
+            for b in &room_names {
+                assert_eq!(a == b, a.cmp(b) == std::cmp::Ordering::Equal);
+            }
+        }
+    }
+
+    #[cfg(feature = "sim")]
+    #[test]
+    fn sim_room_arithmetic() {
+        let sim = RoomName::new("sim").unwrap();
+        let w126n127 = RoomName::new("W126N127").unwrap();
+
+        // the sim room has the canonical coordinates of W127N127
+        assert_eq!((sim.x_coord(), sim.y_coord()), (-128, -128));
+
+        // which only it can reach
+        assert_eq!(sim.checked_add((0, 0)), Some(sim));
+        assert_eq!(sim.checked_add((1, 0)), None);
+        assert_eq!(sim.checked_add((1, 1)), None);
+        assert_eq!(sim.checked_add((-1, 0)), None);
+
+        // and which can't be reached from other rooms
+        assert_eq!(w126n127.checked_add((-1, 0)), None);
+        assert_eq!(w126n127.checked_add((0, 0)), Some(w126n127));
+        assert_eq!(
+            w126n127.checked_add((0, 1)).unwrap().to_string(),
+            "W126N126"
+        );
+
+        assert_eq!(sim + (0, 0), sim);
+        assert_eq!(sim - (0, 0), sim);
+        assert!(std::panic::catch_unwind(|| sim + (1, 0)).is_err());
+        assert!(std::panic::catch_unwind(|| w126n127 - (1, 0)).is_err());
+    }
 }
@@ -0,0 +1,304 @@
+//! A compact set of the tiles in a room.
+use std::fmt;
+
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{terrain_index_to_xy, xy_to_terrain_index, RoomXY, ROOM_AREA};
+
+/// The number of bytes needed to hold one bit for every tile in a room.
+const BYTES: usize = ROOM_AREA.div_ceil(8);
+
+/// The characters of the standard base64 alphabet, in order of their values.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A set of tiles in a room, stored as one bit per tile.
+///
+/// When serialized to a human-readable format such as JSON, the set is a
+/// base64 string of its bits, which takes far less room in memory than a list
+/// of coordinates for all but the smallest sets. Other formats get the raw
+/// bytes.
+///
+/// # Example
+///
+/// ```
+/// use screeps::local::{RoomTileBitset, RoomXY};
+///
+/// let xy = |x, y| RoomXY::try_from((x, y)).unwrap();
+///
+/// let mut tiles = RoomTileBitset::new();
+/// assert!(tiles.insert(xy(10, 10)));
+/// assert!(!tiles.insert(xy(10, 10)));
+/// assert!(tiles.contains(xy(10, 10)));
+/// assert_eq!(tiles.len(), 1);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RoomTileBitset {
+    bits: [u8; BYTES],
+}
+
+impl Default for RoomTileBitset {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoomTileBitset {
+    /// Creates an empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        RoomTileBitset { bits: [0; BYTES] }
+    }
+
+    /// Whether the tile is in the set.
+    #[inline]
+    pub fn contains(&self, xy: RoomXY) -> bool {
+        let (byte, mask) = Self::bit(xy);
+        self.bits[byte] & mask != 0
+    }
+
+    /// Adds a tile to the set, returning whether it wasn't already present.
+    #[inline]
+    pub fn insert(&mut self, xy: RoomXY) -> bool {
+        let (byte, mask) = Self::bit(xy);
+        let added = self.bits[byte] & mask == 0;
+        self.bits[byte] |= mask;
+        added
+    }
+
+    /// Removes a tile from the set, returning whether it was present.
+    #[inline]
+    pub fn remove(&mut self, xy: RoomXY) -> bool {
+        let (byte, mask) = Self::bit(xy);
+        let removed = self.bits[byte] & mask != 0;
+        self.bits[byte] &= !mask;
+        removed
+    }
+
+    /// Removes every tile from the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bits = [0; BYTES];
+    }
+
+    /// The number of tiles in the set.
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    /// Whether the set has no tiles.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&byte| byte == 0)
+    }
+
+    /// Gets an iterator over the tiles in the set, in the order of their
+    /// terrain indices.
+    pub fn iter(&self) -> impl Iterator<Item = RoomXY> + '_ {
+        (0..ROOM_AREA)
+            .filter(|&idx| self.bits[idx / 8] & (1 << (idx % 8)) != 0)
+            .map(terrain_index_to_xy)
+    }
+
+    fn bit(xy: RoomXY) -> (usize, u8) {
+        let idx = xy_to_terrain_index(xy);
+        (idx / 8, 1 << (idx % 8))
+    }
+}
+
+impl fmt::Debug for RoomTileBitset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<RoomXY> for RoomTileBitset {
+    fn from_iter<I: IntoIterator<Item = RoomXY>>(iter: I) -> Self {
+        let mut set = RoomTileBitset::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<RoomXY> for RoomTileBitset {
+    fn extend<I: IntoIterator<Item = RoomXY>>(&mut self, iter: I) {
+        for xy in iter {
+            self.insert(xy);
+        }
+    }
+}
+
+impl Serialize for RoomTileBitset {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&encode_base64(&self.bits))
+        } else {
+            serializer.serialize_bytes(&self.bits)
+        }
+    }
+}
+
+struct RoomTileBitsetVisitor;
+
+impl<'de> Visitor<'de> for RoomTileBitsetVisitor {
+    type Value = RoomTileBitset;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a base64 string or {BYTES} bytes representing a set of room tiles"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let bytes = decode_base64(v).ok_or_else(|| E::custom("invalid base64 tile set"))?;
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let bits = v
+            .try_into()
+            .map_err(|_| E::invalid_length(v.len(), &self))?;
+        Ok(RoomTileBitset { bits })
+    }
+}
+
+impl<'de> Deserialize<'de> for RoomTileBitset {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(RoomTileBitsetVisitor)
+        } else {
+            deserializer.deserialize_bytes(RoomTileBitsetVisitor)
+        }
+    }
+}
+
+/// Encodes bytes as padded base64 with the standard alphabet.
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |group, (i, &b)| group | ((b as u32) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let sextet = (group >> (18 - 6 * i)) & 0x3F;
+                encoded.push(BASE64_ALPHABET[sextet as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes padded base64 with the standard alphabet, returning `None` if the
+/// string isn't valid base64.
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let chunks = s.as_bytes().chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(chunks.len() * 3);
+    let last = chunks.len().saturating_sub(1);
+    for (idx, chunk) in chunks.enumerate() {
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && idx != last) {
+            return None;
+        }
+
+        let mut group = 0u32;
+        for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+            let sextet = BASE64_ALPHABET.iter().position(|&a| a == c)? as u32;
+            group |= sextet << (18 - 6 * i);
+        }
+        decoded.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode_base64, encode_base64, RoomTileBitset};
+    use crate::local::RoomXY;
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::try_from((x, y)).unwrap()
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let tiles: RoomTileBitset = [xy(0, 0), xy(25, 25), xy(49, 0), xy(49, 49)]
+            .into_iter()
+            .collect();
+
+        let serialized = serde_json::to_string(&tiles).unwrap();
+        // 313 bytes of bits make 420 characters of base64, plus the quotes
+        assert_eq!(serialized.len(), 422);
+        assert!(serialized.starts_with("\"AQAAAAAAAgAA"));
+
+        let deserialized: RoomTileBitset = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, tiles);
+        assert_eq!(
+            deserialized.iter().collect::<Vec<_>>(),
+            [xy(0, 0), xy(49, 0), xy(25, 25), xy(49, 49)]
+        );
+
+        let empty: RoomTileBitset =
+            serde_json::from_str(&serde_json::to_string(&RoomTileBitset::new()).unwrap()).unwrap();
+        assert!(empty.is_empty());
+
+        assert!(serde_json::from_str::<RoomTileBitset>("\"AQAB\"").is_err());
+        assert!(serde_json::from_str::<RoomTileBitset>("\"not base64!\"").is_err());
+    }
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(encode_base64(b"M"), "TQ==");
+        assert_eq!(encode_base64(b"Ma"), "TWE=");
+        assert_eq!(encode_base64(b"Man"), "TWFu");
+        for s in ["", "M", "Ma", "Man", "Many"] {
+            assert_eq!(
+                decode_base64(&encode_base64(s.as_bytes())).unwrap(),
+                s.as_bytes()
+            );
+        }
+        assert_eq!(decode_base64("TQ=="), Some(b"M".to_vec()));
+        assert_eq!(decode_base64("T==="), None);
+        assert_eq!(decode_base64("TQ==TWFu"), None);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut tiles = RoomTileBitset::new();
+        assert!(tiles.insert(xy(3, 4)));
+        assert!(tiles.insert(xy(4, 3)));
+        assert_eq!(tiles.len(), 2);
+        assert!(tiles.remove(xy(3, 4)));
+        assert!(!tiles.remove(xy(3, 4)));
+        assert!(!tiles.contains(xy(3, 4)));
+        assert!(tiles.contains(xy(4, 3)));
+
+        tiles.clear();
+        assert!(tiles.is_empty());
+    }
+}
@@ -0,0 +1,344 @@
+//! Paths serialized in the engine's compact string format, as used by
+//! `Room.serializePath`, `Room.deserializePath`, and `Creep.moveByPath`, and
+//! as bare strings of direction digits.
+use std::{error::Error, fmt};
+
+use num_traits::FromPrimitive;
+
+use crate::constants::Direction;
+
+use super::{Position, RoomCoordinate, RoomXY};
+
+/// A single step of a path deserialized by [`deserialize_path`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PathStep {
+    /// The position reached by this step.
+    pub xy: RoomXY,
+    /// The direction moved to reach [`PathStep::xy`] from the previous tile.
+    pub direction: Direction,
+}
+
+/// Error returned when deserializing a path from a string not in the format
+/// produced by [`serialize_path`] or `Room.serializePath`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathParseError {
+    /// The path didn't start with a valid two-digit x and y coordinate for its
+    /// first step.
+    InvalidStart,
+    /// The character at this byte index wasn't a direction from `1` to `8`.
+    InvalidDirection(usize),
+    /// The step at this byte index would move outside of the room.
+    OutOfBounds(usize),
+}
+
+impl fmt::Display for PathParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathParseError::InvalidStart => {
+                write!(
+                    f,
+                    "serialized path must start with two two-digit coordinates"
+                )
+            }
+            PathParseError::InvalidDirection(idx) => {
+                write!(f, "serialized path has an invalid direction at index {idx}")
+            }
+            PathParseError::OutOfBounds(idx) => {
+                write!(f, "serialized path leaves the room at index {idx}")
+            }
+        }
+    }
+}
+
+impl Error for PathParseError {}
+
+/// Serializes a path into the string format used by `Room.serializePath`,
+/// which can be stored in memory and passed to [`Creep::move_by_path`].
+///
+/// `steps` starts with the tile the path begins from, followed by each tile
+/// moved to. The serialized form is the two-digit x and y coordinates of the
+/// first tile moved to, followed by the direction digit of every move, which
+/// is what lets `moveByPath` find a creep's place along the path from the tile
+/// it's standing on.
+///
+/// A path without any moves serializes to an empty string.
+///
+/// # Panics
+///
+/// Panics if any two consecutive positions in `steps` aren't adjacent.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{local::serialize_path, RoomXY};
+///
+/// let steps = [(10, 10), (11, 9), (12, 9), (12, 10)].map(|xy| RoomXY::try_from(xy).unwrap());
+/// assert_eq!(serialize_path(&steps), "1109235");
+/// ```
+///
+/// [`Creep::move_by_path`]: crate::objects::Creep::move_by_path
+pub fn serialize_path(steps: &[RoomXY]) -> String {
+    let Some(first_move) = steps.get(1) else {
+        return String::new();
+    };
+
+    let mut serialized = format!("{:02}{:02}", first_move.x.u8(), first_move.y.u8());
+    for pair in steps.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        let direction = enum_iterator::all::<Direction>()
+            .find(|&direction| from.checked_add_direction(direction) == Some(to))
+            .unwrap_or_else(|| panic!("path steps {from} and {to} aren't adjacent"));
+        serialized.push(char::from(b'0' + direction as u8));
+    }
+
+    serialized
+}
+
+/// Deserializes a path from the string format used by `Room.serializePath`
+/// and produced by [`serialize_path`].
+///
+/// Like `Room.deserializePath`, the returned steps don't include the tile the
+/// path begins from; the first step is at the coordinates at the start of the
+/// string, with the direction moved to reach it.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{
+///     local::{deserialize_path, PathStep},
+///     Direction, RoomXY,
+/// };
+///
+/// let steps = deserialize_path("1109235").unwrap();
+/// assert_eq!(
+///     steps,
+///     vec![
+///         PathStep {
+///             xy: RoomXY::try_from((11, 9)).unwrap(),
+///             direction: Direction::TopRight,
+///         },
+///         PathStep {
+///             xy: RoomXY::try_from((12, 9)).unwrap(),
+///             direction: Direction::Right,
+///         },
+///         PathStep {
+///             xy: RoomXY::try_from((12, 10)).unwrap(),
+///             direction: Direction::Bottom,
+///         },
+///     ]
+/// );
+/// ```
+pub fn deserialize_path(s: &str) -> Result<Vec<PathStep>, PathParseError> {
+    if s.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let coord = |digits: Option<&str>| {
+        digits
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|digits| digits.parse::<u8>().ok())
+            .and_then(|coord| RoomCoordinate::new(coord).ok())
+            .ok_or(PathParseError::InvalidStart)
+    };
+    let mut xy = RoomXY {
+        x: coord(s.get(0..2))?,
+        y: coord(s.get(2..4))?,
+    };
+
+    let mut steps = Vec::with_capacity(s.len().saturating_sub(4));
+    for (idx, b) in s.bytes().enumerate().skip(4) {
+        let direction = (b as char)
+            .to_digit(10)
+            .and_then(Direction::from_u32)
+            .ok_or(PathParseError::InvalidDirection(idx))?;
+
+        // the coordinates are those of the first step, so only later steps move
+        if idx > 4 {
+            xy = xy
+                .checked_add_direction(direction)
+                .ok_or(PathParseError::OutOfBounds(idx))?;
+        }
+        steps.push(PathStep { xy, direction });
+    }
+
+    Ok(steps)
+}
+
+/// Serializes a path as a string of the direction digits from `1` to `8`
+/// moved along each step, without the coordinates that [`serialize_path`]
+/// starts with.
+///
+/// `path` starts with the position the path begins from, followed by each
+/// position moved to, and may cross room borders. The start position must be
+/// stored separately to turn the digits back into a path with
+/// [`digits_to_path`].
+///
+/// # Panics
+///
+/// Panics if any two consecutive positions in `path` aren't adjacent.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{local::path_to_digits, Position};
+///
+/// let path = [(10, 10), (11, 9), (12, 9), (12, 10)].map(|(x, y)| Position::from_world_coords(x, y));
+/// assert_eq!(path_to_digits(&path), "235");
+/// ```
+pub fn path_to_digits(path: &[Position]) -> String {
+    path.windows(2)
+        .map(|pair| {
+            let (from, to) = (pair[0], pair[1]);
+            let direction = enum_iterator::all::<Direction>()
+                .find(|&direction| from.checked_add_direction(direction) == Ok(to))
+                .unwrap_or_else(|| panic!("path positions {from} and {to} aren't adjacent"));
+            char::from(b'0' + direction as u8)
+        })
+        .collect()
+}
+
+/// Follows a string of direction digits produced by [`path_to_digits`] from
+/// the start position, returning every position along the path including the
+/// start.
+///
+/// Moves past a room's edge continue into the neighboring room.
+pub fn digits_to_path(start: Position, digits: &str) -> Result<Vec<Position>, PathParseError> {
+    let mut pos = start;
+    let mut path = Vec::with_capacity(digits.len() + 1);
+    path.push(pos);
+    for (idx, b) in digits.bytes().enumerate() {
+        let direction = (b as char)
+            .to_digit(10)
+            .and_then(Direction::from_u32)
+            .ok_or(PathParseError::InvalidDirection(idx))?;
+        pos = pos
+            .checked_add_direction(direction)
+            .map_err(|_| PathParseError::OutOfBounds(idx))?;
+        path.push(pos);
+    }
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local::RoomName;
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::try_from((x, y)).unwrap()
+    }
+
+    #[test]
+    fn engine_serialized_path_roundtrip() {
+        // a path from (5, 8) to (9, 8), as serialized by `Room.serializePath`
+        let serialized = "06094332";
+        let steps = deserialize_path(serialized).unwrap();
+        assert_eq!(
+            steps,
+            vec![
+                PathStep {
+                    xy: xy(6, 9),
+                    direction: Direction::BottomRight,
+                },
+                PathStep {
+                    xy: xy(7, 9),
+                    direction: Direction::Right,
+                },
+                PathStep {
+                    xy: xy(8, 9),
+                    direction: Direction::Right,
+                },
+                PathStep {
+                    xy: xy(9, 8),
+                    direction: Direction::TopRight,
+                },
+            ]
+        );
+
+        let tiles: Vec<RoomXY> = std::iter::once(xy(5, 8))
+            .chain(steps.iter().map(|step| step.xy))
+            .collect();
+        assert_eq!(serialize_path(&tiles), serialized);
+    }
+
+    #[test]
+    fn serialize_path_without_moves() {
+        assert_eq!(serialize_path(&[]), "");
+        assert_eq!(serialize_path(&[xy(25, 25)]), "");
+        assert_eq!(deserialize_path(""), Ok(vec![]));
+
+        // the engine accepts coordinates without any directions
+        assert_eq!(deserialize_path("2525"), Ok(vec![]));
+    }
+
+    #[test]
+    fn serialize_path_pads_coordinates() {
+        assert_eq!(serialize_path(&[xy(1, 1), xy(0, 0)]), "00008");
+        assert_eq!(serialize_path(&[xy(48, 48), xy(49, 49)]), "49494");
+    }
+
+    #[test]
+    #[should_panic]
+    fn serialize_path_non_adjacent() {
+        serialize_path(&[xy(10, 10), xy(12, 10)]);
+    }
+
+    #[test]
+    fn direction_digits_roundtrip() {
+        let w0n1: RoomName = "W0N1".parse().unwrap();
+        let e0n1: RoomName = "E0N1".parse().unwrap();
+        let pos = |x: u8, y: u8, room| Position::new(xy(x, y).x, xy(x, y).y, room);
+
+        // crossing the border from W0N1 into E0N1
+        let path = vec![
+            pos(47, 20, w0n1),
+            pos(48, 21, w0n1),
+            pos(49, 21, w0n1),
+            pos(0, 20, e0n1),
+            pos(1, 20, e0n1),
+        ];
+        let digits = path_to_digits(&path);
+        assert_eq!(digits, "4323");
+        assert_eq!(digits_to_path(path[0], &digits), Ok(path));
+
+        assert_eq!(path_to_digits(&[pos(25, 25, w0n1)]), "");
+        assert_eq!(
+            digits_to_path(pos(25, 25, w0n1), ""),
+            Ok(vec![pos(25, 25, w0n1)])
+        );
+    }
+
+    #[test]
+    fn invalid_direction_digits() {
+        let start = Position::from_world_coords(10, 10);
+        assert_eq!(
+            digits_to_path(start, "3390"),
+            Err(PathParseError::InvalidDirection(2))
+        );
+        assert_eq!(
+            digits_to_path(start, "3a"),
+            Err(PathParseError::InvalidDirection(1))
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid_paths() {
+        assert_eq!(deserialize_path("1"), Err(PathParseError::InvalidStart));
+        assert_eq!(deserialize_path("1a103"), Err(PathParseError::InvalidStart));
+        assert_eq!(deserialize_path("+1103"), Err(PathParseError::InvalidStart));
+        assert_eq!(deserialize_path("50103"), Err(PathParseError::InvalidStart));
+        assert_eq!(
+            deserialize_path("101039"),
+            Err(PathParseError::InvalidDirection(5))
+        );
+        assert_eq!(
+            deserialize_path("101030"),
+            Err(PathParseError::InvalidDirection(5))
+        );
+        assert_eq!(
+            deserialize_path("0000111"),
+            Err(PathParseError::OutOfBounds(5))
+        );
+    }
+}
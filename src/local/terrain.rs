@@ -2,9 +2,12 @@ use std::mem::MaybeUninit;
 
 use js_sys::Uint8Array;
 
-use crate::{constants::Terrain, objects::RoomTerrain};
+use crate::{
+    constants::{ExitDirection, Terrain, ROOM_SIZE},
+    objects::RoomTerrain,
+};
 
-use super::{xy_to_terrain_index, RoomXY, ROOM_AREA};
+use super::{xy_to_terrain_index, RoomCoordinate, RoomXY, ROOM_AREA};
 
 #[derive(Debug, Clone)]
 pub struct LocalRoomTerrain {
@@ -77,3 +80,136 @@ impl From<RoomTerrain> for LocalRoomTerrain {
         })
     }
 }
+
+/// Finds the exit tile on the given edge of the room which is closest to a
+/// position, for leaving toward the neighboring room in that direction.
+///
+/// Tiles are compared by range from `from`, with ties going to the tile that's
+/// closest along the edge, then to the tile with the lower coordinate. Returns
+/// `None` if the edge has no exit tiles.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{
+///     constants::ExitDirection,
+///     local::{nearest_exit_tile, LocalRoomTerrain},
+///     RoomXY,
+/// };
+///
+/// let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; 2500]));
+/// let from = RoomXY::try_from((10, 30)).unwrap();
+///
+/// assert_eq!(
+///     nearest_exit_tile(from, ExitDirection::Left, &terrain),
+///     Some(RoomXY::try_from((0, 30)).unwrap())
+/// );
+/// ```
+pub fn nearest_exit_tile(
+    from: RoomXY,
+    dir: ExitDirection,
+    terrain: &LocalRoomTerrain,
+) -> Option<RoomXY> {
+    let min = RoomCoordinate::new_const::<0>();
+    let max = RoomCoordinate::new_const::<{ ROOM_SIZE - 1 }>();
+    // corners are never exits, so only the tiles between them are checked
+    let first = RoomCoordinate::new_const::<1>();
+    let last = RoomCoordinate::new_const::<{ ROOM_SIZE - 2 }>();
+    let edge = RoomCoordinate::range(first, last).map(move |c| match dir {
+        ExitDirection::Top => RoomXY { x: c, y: min },
+        ExitDirection::Right => RoomXY { x: max, y: c },
+        ExitDirection::Bottom => RoomXY { x: c, y: max },
+        ExitDirection::Left => RoomXY { x: min, y: c },
+    });
+
+    let diff = |a: RoomCoordinate, b: RoomCoordinate| a.u8().abs_diff(b.u8());
+    edge.filter(|&xy| terrain.get(xy) != Terrain::Wall)
+        .min_by_key(|&xy| {
+            let (dx, dy) = (diff(from.x, xy.x), diff(from.y, xy.y));
+            let along_edge = match dir {
+                ExitDirection::Top | ExitDirection::Bottom => dx,
+                ExitDirection::Left | ExitDirection::Right => dy,
+            };
+            (dx.max(dy), along_edge)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::try_from((x, y)).unwrap()
+    }
+
+    #[test]
+    fn nearest_exit_on_top_edge() {
+        // the top edge is walled off except for exits at x 5 to 8, x 20, and
+        // x 30 to 34
+        let mut bits = Box::new([0; ROOM_AREA]);
+        for x in 0..50 {
+            if !matches!(x, 5..=8 | 20 | 30..=34) {
+                bits[xy_to_terrain_index(xy(x, 0))] = 1;
+            }
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+        let top = ExitDirection::Top;
+
+        assert_eq!(nearest_exit_tile(xy(26, 2), top, &terrain), Some(xy(30, 0)));
+        // ranges to x 20 and 30 tie at 10, and x 20 is closer along the edge
+        assert_eq!(
+            nearest_exit_tile(xy(24, 10), top, &terrain),
+            Some(xy(20, 0))
+        );
+        // x 20 and 30 tie both by range and along the edge
+        assert_eq!(nearest_exit_tile(xy(25, 2), top, &terrain), Some(xy(20, 0)));
+        // from the bottom edge every exit is in range 49, so the one straight
+        // up wins
+        assert_eq!(
+            nearest_exit_tile(xy(20, 49), top, &terrain),
+            Some(xy(20, 0))
+        );
+        assert_eq!(nearest_exit_tile(xy(0, 5), top, &terrain), Some(xy(5, 0)));
+        assert_eq!(nearest_exit_tile(xy(49, 1), top, &terrain), Some(xy(34, 0)));
+
+        // the other edges are fully open
+        assert_eq!(
+            nearest_exit_tile(xy(24, 10), ExitDirection::Bottom, &terrain),
+            Some(xy(24, 49))
+        );
+    }
+
+    #[test]
+    fn corners_are_not_exits() {
+        let terrain = LocalRoomTerrain::new_from_bits(Box::new([0; ROOM_AREA]));
+
+        // (0, 0) is as close as (1, 0), and closer along the edge, but it's a
+        // corner
+        assert_eq!(
+            nearest_exit_tile(xy(0, 3), ExitDirection::Top, &terrain),
+            Some(xy(1, 0))
+        );
+        assert_eq!(
+            nearest_exit_tile(xy(49, 49), ExitDirection::Right, &terrain),
+            Some(xy(49, 48))
+        );
+    }
+
+    #[test]
+    fn no_exit_on_walled_edge() {
+        let mut bits = Box::new([0; ROOM_AREA]);
+        for y in 0..50 {
+            bits[xy_to_terrain_index(xy(49, y))] = 1;
+        }
+        let terrain = LocalRoomTerrain::new_from_bits(bits);
+
+        assert_eq!(
+            nearest_exit_tile(xy(25, 25), ExitDirection::Right, &terrain),
+            None
+        );
+        assert_eq!(
+            nearest_exit_tile(xy(25, 25), ExitDirection::Left, &terrain),
+            Some(xy(0, 25))
+        );
+    }
+}
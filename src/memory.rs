@@ -10,12 +10,21 @@
 //! wasm_bindgen compatible type with the properly access functions you need via
 //! [`wasm_bindgen::JsCast`].
 //!
+//! Alternatively, [`root_cached`] parses [`raw_memory::get`] once per global
+//! and keeps the resulting object across ticks, avoiding the cost of the
+//! game parsing `Memory` every tick.
+//!
 //! [`ROOT`]: crate::memory::ROOT
 //! [`Creep::memory`]: crate::objects::Creep::memory
 //! [`StructureSpawn::memory`]: crate::objects::StructureSpawn::memory
-use js_sys::Object;
+//! [`raw_memory::get`]: crate::raw_memory::get
+use std::cell::RefCell;
+
+use js_sys::{JsString, Object};
 use wasm_bindgen::prelude::*;
 
+use crate::raw_memory;
+
 #[wasm_bindgen]
 extern "C" {
     /// Get a reference to the `Memory` global object. Note that this object
@@ -25,3 +34,104 @@ extern "C" {
     pub static ROOT: Object;
 
 }
+
+#[wasm_bindgen]
+extern "C" {
+    /// Parse a [`JsString`] of JSON without copying it into Rust memory.
+    #[wasm_bindgen(js_namespace = JSON, js_name = parse, catch)]
+    fn parse_json(text: &JsString) -> Result<JsValue, JsValue>;
+}
+
+thread_local! {
+    static ROOT_CACHE: RefCell<Option<JsValue>> = const { RefCell::new(None) };
+}
+
+/// Get the memory object parsed from [`raw_memory::get`], parsing it only on
+/// the first call and returning the same object on every later call.
+///
+/// The object is never written back by the game; serialize it and pass it to
+/// [`raw_memory::set`] to persist any changes. If the raw memory fails to
+/// parse, the error thrown by `JSON.parse` is returned and nothing is cached,
+/// so the next call tries to parse it again.
+///
+/// The cache lives in the wasm module's memory, which is discarded along with
+/// the rest of the JavaScript heap on a global reset, so the first call after
+/// a reset always parses fresh memory. Call [`invalidate_cache`] to reparse
+/// within the same global, such as after memory has been [`raw_memory::set`]
+/// by other code.
+///
+/// [`raw_memory::get`]: crate::raw_memory::get
+/// [`raw_memory::set`]: crate::raw_memory::set
+pub fn root_cached() -> Result<JsValue, JsValue> {
+    ROOT_CACHE.with(|cache| get_or_parse(cache, || parse_json(&raw_memory::get())))
+}
+
+/// Clear the memory object cached by [`root_cached`], causing the next call to
+/// parse [`raw_memory::get`] again.
+///
+/// [`raw_memory::get`]: crate::raw_memory::get
+pub fn invalidate_cache() {
+    ROOT_CACHE.with(|cache| cache.borrow_mut().take());
+}
+
+/// Gets the cached value, or parses and caches it, leaving the cache empty if
+/// parsing fails.
+fn get_or_parse<T: Clone, E>(
+    cache: &RefCell<Option<T>>,
+    parse: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut cache = cache.borrow_mut();
+    if let Some(cached) = cache.as_ref() {
+        return Ok(cached.clone());
+    }
+    let parsed = parse()?;
+    Ok(cache.insert(parsed).clone())
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::{Cell, RefCell};
+
+    use super::get_or_parse;
+
+    #[test]
+    fn parse_once_until_invalidated() {
+        let cache = RefCell::new(None);
+        let parses = Cell::new(0);
+        let parse = || {
+            parses.set(parses.get() + 1);
+            Ok::<_, ()>(format!("memory {}", parses.get()))
+        };
+
+        assert_eq!(get_or_parse(&cache, parse), Ok("memory 1".to_owned()));
+        assert_eq!(get_or_parse(&cache, parse), Ok("memory 1".to_owned()));
+        assert_eq!(parses.get(), 1);
+
+        // invalidating the same way `invalidate_cache` does forces a reparse
+        cache.borrow_mut().take();
+        assert_eq!(get_or_parse(&cache, parse), Ok("memory 2".to_owned()));
+        assert_eq!(get_or_parse(&cache, parse), Ok("memory 2".to_owned()));
+        assert_eq!(parses.get(), 2);
+    }
+
+    #[test]
+    fn parse_error_is_not_cached() {
+        let cache = RefCell::new(None);
+
+        // corrupted raw memory fails to parse, and is tried again next time
+        assert_eq!(
+            get_or_parse(&cache, || Err::<String, _>("unexpected token")),
+            Err("unexpected token")
+        );
+        assert_eq!(*cache.borrow(), None);
+
+        assert_eq!(
+            get_or_parse(&cache, || Ok::<_, &str>("{}".to_owned())),
+            Ok("{}".to_owned())
+        );
+        assert_eq!(
+            get_or_parse(&cache, || Err("not called once cached")),
+            Ok("{}".to_owned())
+        );
+    }
+}
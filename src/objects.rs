@@ -54,6 +54,12 @@ mod room_objects {
         StructureTower, StructureWall, Tombstone,
     };
 
+    pub use super::impls::{
+        assign_spawns, select_body_tier, should_be_public, spawn_time_remaining,
+        tower_sustained_shots, ControllerLevelChange, ControllerTracker, DeliverEnergyError,
+        ResourceMove, SpawnRequest,
+    };
+
     #[cfg(feature = "seasonal-season-1")]
     pub use super::impls::{ScoreCollector, ScoreContainer};
 
@@ -58,8 +58,8 @@ mod reactor;
 pub use self::{
     construction_site::ConstructionSite,
     cost_matrix::CostMatrix,
-    creep::{BodyPart, Creep},
-    creep_shared::MoveToOptions,
+    creep::{BodyPart, Creep, DeliverEnergyError},
+    creep_shared::{MoveToOptions, ResourceMove},
     deposit::Deposit,
     flag::Flag,
     mineral::Mineral,
@@ -81,7 +81,9 @@ pub use self::{
     store::Store,
     structure::Structure,
     structure_container::StructureContainer,
-    structure_controller::{Reservation, Sign, StructureController},
+    structure_controller::{
+        ControllerLevelChange, ControllerTracker, Reservation, Sign, StructureController,
+    },
     structure_extension::StructureExtension,
     structure_extractor::StructureExtractor,
     structure_factory::StructureFactory,
@@ -94,12 +96,15 @@ pub use self::{
     structure_portal::{InterShardPortalDestination, PortalDestination, StructurePortal},
     structure_power_bank::StructurePowerBank,
     structure_power_spawn::StructurePowerSpawn,
-    structure_rampart::StructureRampart,
+    structure_rampart::{should_be_public, StructureRampart},
     structure_road::StructureRoad,
-    structure_spawn::{SpawnOptions, Spawning, StructureSpawn},
+    structure_spawn::{
+        assign_spawns, select_body_tier, spawn_time_remaining, SpawnOptions, SpawnRequest,
+        Spawning, StructureSpawn,
+    },
     structure_storage::StructureStorage,
     structure_terminal::StructureTerminal,
-    structure_tower::StructureTower,
+    structure_tower::{tower_sustained_shots, StructureTower},
     structure_wall::StructureWall,
     tombstone::Tombstone,
 };
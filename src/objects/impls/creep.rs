@@ -1,14 +1,22 @@
+use std::{cell::RefCell, collections::HashMap};
+
 use js_sys::{Array, JsString};
+use serde::de::DeserializeOwned;
 use wasm_bindgen::{prelude::*, JsCast};
 
 use crate::{
-    constants::{Direction, ErrorCode, Part, ResourceType},
+    constants::{
+        find, Boost, Direction, ErrorCode, Part, ResourceType, CARRY_CAPACITY, MOVE_POWER,
+    },
+    enums::StructureObject,
+    game,
+    local::RawObjectId,
     objects::{
-        ConstructionSite, Owner, Resource, RoomObject, Store, Structure, StructureController,
+        ConstructionSite, Owner, Resource, Room, RoomObject, Store, Structure, StructureController,
     },
     pathfinder::SingleRoomCostResult,
     prelude::*,
-    CostMatrix, MoveToOptions, RoomName, RoomPosition,
+    CostMatrix, MoveToOptions, Position, RoomName, RoomPosition,
 };
 
 #[cfg(feature = "seasonal-season-5")]
@@ -60,7 +68,10 @@ extern "C" {
     fn owner_internal(this: &Creep) -> Owner;
 
     #[wasm_bindgen(structural, method, getter = saying)]
-    fn saying_internal(this: &Creep) -> Option<JsString>;
+    fn saying_internal(this: &Creep) -> Option<String>;
+
+    #[wasm_bindgen(structural, method, getter = saying)]
+    fn saying_jsstring_internal(this: &Creep) -> Option<JsString>;
 
     #[wasm_bindgen(structural, method, getter = spawning)]
     fn spawning_internal(this: &Creep) -> bool;
@@ -219,6 +230,60 @@ impl Creep {
         self.set_memory_internal(val)
     }
 
+    /// The `role` field of the creep's memory, deserialized into a type such as
+    /// an enum of roles.
+    ///
+    /// The role can be stored in any form `R` deserializes from, such as a
+    /// string for a unit enum, a number for an enum deriving
+    /// `serde_repr::Deserialize_repr`, or an object for an enum with data.
+    ///
+    /// Returns `None` if the creep's memory has no `role`, or if it doesn't
+    /// deserialize into `R`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::game;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// #[serde(rename_all = "lowercase")]
+    /// enum Role {
+    ///     Harvester,
+    ///     Upgrader,
+    /// }
+    ///
+    /// for creep in game::creeps().values() {
+    ///     match creep.role::<Role>() {
+    ///         Some(Role::Harvester) => { /* harvest */ }
+    ///         Some(Role::Upgrader) => { /* upgrade */ }
+    ///         None => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn role<R: DeserializeOwned>(&self) -> Option<R> {
+        let memory = self.memory();
+        if !memory.is_object() {
+            return None;
+        }
+        let role = js_sys::Reflect::get(&memory, &JsValue::from_str("role")).ok()?;
+        if role.is_undefined() {
+            return None;
+        }
+        serde_wasm_bindgen::from_value(role).ok()
+    }
+
+    /// The `role` field of the creep's memory, if it's a string.
+    pub(crate) fn memory_role(&self) -> Option<String> {
+        let memory = self.memory();
+        if !memory.is_object() {
+            return None;
+        }
+        js_sys::Reflect::get(&memory, &JsValue::from_str("role"))
+            .ok()
+            .and_then(|role| role.as_string())
+    }
+
     /// Whether this creep is owned by the player.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.my)
@@ -233,13 +298,24 @@ impl Creep {
         self.owner_internal()
     }
 
-    /// What the creep said last tick.
+    /// What the creep said last tick as a [`String`], or `None` if it
+    /// didn't say anything. Messages from other players' creeps are only
+    /// visible if they were said publicly.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.saying)
-    pub fn saying(&self) -> Option<JsString> {
+    pub fn saying(&self) -> Option<String> {
         self.saying_internal()
     }
 
+    /// What the creep said last tick as a [`JsString`], or `None` if it
+    /// didn't say anything. Messages from other players' creeps are only
+    /// visible if they were said publicly.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.saying)
+    pub fn saying_jsstring(&self) -> Option<JsString> {
+        self.saying_jsstring_internal()
+    }
+
     /// Whether the creep is still spawning.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.spawning)
@@ -262,6 +338,41 @@ impl Creep {
         self.ticks_to_live_internal()
     }
 
+    /// Sort creeps by their [`Creep::ticks_to_live`], in ascending or
+    /// descending order. Creeps which are still spawning, and so have no ticks
+    /// to live yet, are always sorted last.
+    ///
+    /// Each creep's ticks to live is read only once.
+    pub fn sort_by_ttl(creeps: &mut [Creep], ascending: bool) {
+        creeps.sort_by_cached_key(|creep| ttl_sort_key(creep.ticks_to_live(), ascending));
+    }
+
+    /// The number of ticks the creep takes to move each tile onto terrain with
+    /// the given fatigue cost per body part, from [`movement_cost`], carrying
+    /// what's currently in its store.
+    ///
+    /// Every part other than [`Part::Move`] and [`Part::Carry`] generates
+    /// fatigue, as do carry parts which are holding resources; body parts fill
+    /// from first to last, each holding [`CARRY_CAPACITY`] times its boost.
+    /// Each active move part then removes [`MOVE_POWER`] fatigue per tick, times
+    /// its boost.
+    ///
+    /// Creeps which generate no fatigue, such as a creep of only move parts,
+    /// always take 1 tick per tile. Creeps which generate fatigue but have no
+    /// active move parts can't move on their own, and return [`u32::MAX`].
+    ///
+    /// [`movement_cost`]: crate::constants::movement_cost
+    /// [`CARRY_CAPACITY`]: crate::constants::CARRY_CAPACITY
+    /// [`MOVE_POWER`]: crate::constants::MOVE_POWER
+    pub fn ticks_per_step(&self, terrain_cost: u32) -> u32 {
+        let body = self
+            .body()
+            .into_iter()
+            .map(|part| (part.part(), part.hits(), part.boost()));
+        let carried = self.store().get_used_capacity(None).unwrap_or(0);
+        ticks_per_step_for_body(body, carried, terrain_cost)
+    }
+
     /// Attack a target in melee range using a creep's attack parts.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.attack)
@@ -288,6 +399,29 @@ impl Creep {
         ErrorCode::result_from_i8(self.build_internal(target))
     }
 
+    /// Build a [`ConstructionSite`] like [`Creep::build`], returning how much
+    /// the site's progress increased since this creep's tracked build on the
+    /// previous tick.
+    ///
+    /// Progress is recorded in Rust memory rather than `Memory`, so the amount
+    /// is `None` on the first tick building a site, whenever the creep skipped
+    /// a tick or switched sites, and after the global environment is reset.
+    /// The increase includes work done by any other creeps building the same
+    /// site.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.build)
+    pub fn build_tracked(&self, target: &ConstructionSite) -> Result<Option<u32>, ErrorCode> {
+        let built = target.try_raw_id().and_then(|site| {
+            BUILD_TRACKER.with(|tracker| {
+                tracker
+                    .borrow_mut()
+                    .record(game::time(), self.name(), site, target.progress())
+            })
+        });
+        self.build(target)?;
+        Ok(built)
+    }
+
     /// Cancel an a successfully called creep function from earlier in the tick,
     /// with a [`JsString`] that must contain the JS version of the function
     /// name.
@@ -386,11 +520,96 @@ impl Creep {
 
     /// Move one square in the specified direction.
     ///
+    /// Unlike [`SharedCreepProperties::move_to`], this does no pathfinding,
+    /// making it the cheapest way to follow a path you've already found and
+    /// stored.
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.move)
     pub fn move_direction(&self, direction: Direction) -> Result<(), ErrorCode> {
         ErrorCode::result_from_i8(self.move_direction_internal(direction))
     }
 
+    /// Move toward a target position, using [`Creep::move_direction`] if the
+    /// target is adjacent and in the same room, or falling back to
+    /// [`SharedCreepProperties::move_to`] for more distant targets. Does
+    /// nothing if the creep is already at the target position.
+    ///
+    /// Note that the fallback to `move_to` will store data in
+    /// `Memory.creeps[creep_name]`; see [`SharedCreepProperties::move_to`].
+    pub fn step_toward(&self, target: Position) -> Result<(), ErrorCode> {
+        match StepIntent::between(self.pos(), target) {
+            StepIntent::Arrived => Ok(()),
+            StepIntent::Adjacent(direction) => self.move_direction(direction),
+            StepIntent::Distant => SharedCreepProperties::move_to(self, target),
+        }
+    }
+
+    /// Transfer energy to the closest spawn, extension, or tower of yours in
+    /// the given room which has free capacity for it.
+    ///
+    /// If the transfer fails, the error includes the chosen target; with
+    /// [`ErrorCode::NotInRange`], move the creep toward it and call this again
+    /// once in range to complete the transfer.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{game, DeliverEnergyError, ErrorCode, HasPosition, SharedCreepProperties};
+    ///
+    /// let creep = game::creeps().get("Bob".to_owned()).unwrap();
+    /// let room = creep.room().unwrap();
+    ///
+    /// if let Err(DeliverEnergyError::Transfer(ErrorCode::NotInRange, target)) =
+    ///     creep.deliver_energy(&room)
+    /// {
+    ///     let _ = creep.move_to(target.pos());
+    /// }
+    /// ```
+    pub fn deliver_energy(&self, room: &Room) -> Result<(), DeliverEnergyError> {
+        let candidates = room
+            .find(find::MY_STRUCTURES, None)
+            .into_iter()
+            .filter(|structure| {
+                matches!(
+                    structure,
+                    StructureObject::StructureSpawn(_)
+                        | StructureObject::StructureExtension(_)
+                        | StructureObject::StructureTower(_)
+                )
+            })
+            .filter_map(|structure| {
+                let free_capacity = structure
+                    .as_has_store()?
+                    .store()
+                    .get_free_capacity(Some(ResourceType::Energy))?;
+                Some((structure.pos(), free_capacity, structure))
+            });
+
+        let target =
+            closest_refill_target(self.pos(), candidates).ok_or(DeliverEnergyError::NoTarget)?;
+        // spawns, extensions, and towers are all transferable
+        let Some(transferable) = target.as_transferable() else {
+            return Err(DeliverEnergyError::NoTarget);
+        };
+
+        SharedCreepProperties::transfer(self, transferable, ResourceType::Energy, None)
+            .map_err(|err| DeliverEnergyError::Transfer(err, target))
+    }
+
+    /// Withdraw as much energy as possible from a target, limited by the
+    /// energy in the target and the free capacity of the creep.
+    ///
+    /// This is [`SharedCreepProperties::withdraw`] for
+    /// [`ResourceType::Energy`], without an amount.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.withdraw)
+    pub fn withdraw_energy<T>(&self, target: &T) -> Result<(), ErrorCode>
+    where
+        T: Withdrawable + ?Sized,
+    {
+        SharedCreepProperties::withdraw(self, target, ResourceType::Energy, None)
+    }
+
     /// Accept an attempt by another creep to pull this one.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Creep.move)
@@ -513,6 +732,153 @@ impl Creep {
     }
 }
 
+/// The movement intent [`Creep::step_toward`] uses to reach a target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StepIntent {
+    Arrived,
+    Adjacent(Direction),
+    Distant,
+}
+
+impl StepIntent {
+    fn between(from: Position, to: Position) -> StepIntent {
+        if from == to {
+            StepIntent::Arrived
+        } else if from.is_near_to(to) {
+            // positions are distinct and adjacent, so a direction always exists
+            from.get_direction_to(to)
+                .map(StepIntent::Adjacent)
+                .unwrap_or(StepIntent::Distant)
+        } else {
+            StepIntent::Distant
+        }
+    }
+}
+
+thread_local! {
+    static BUILD_TRACKER: RefCell<BuildTracker> = RefCell::new(BuildTracker::default());
+}
+
+/// Construction site progress recorded by [`Creep::build_tracked`], keeping
+/// only the records from the current and previous tick.
+#[derive(Default)]
+struct BuildTracker {
+    tick: Option<u32>,
+    previous: HashMap<String, (RawObjectId, u32)>,
+    current: HashMap<String, (RawObjectId, u32)>,
+}
+
+impl BuildTracker {
+    /// Records the creep's site progress this tick, returning the increase
+    /// since the creep's record for the same site on the previous tick.
+    fn record(
+        &mut self,
+        tick: u32,
+        creep: String,
+        site: RawObjectId,
+        progress: u32,
+    ) -> Option<u32> {
+        if self.tick != Some(tick) {
+            if self
+                .tick
+                .is_some_and(|last| last.checked_add(1) == Some(tick))
+            {
+                self.previous = std::mem::take(&mut self.current);
+            } else {
+                self.previous.clear();
+                self.current.clear();
+            }
+            self.tick = Some(tick);
+        }
+
+        let built = self
+            .previous
+            .get(&creep)
+            .filter(|&&(previous_site, _)| previous_site == site)
+            .map(|&(_, previous_progress)| progress.saturating_sub(previous_progress));
+        self.current.insert(creep, (site, progress));
+        built
+    }
+}
+
+/// Key for sorting by ticks to live, placing creeps without any last.
+fn ttl_sort_key(ticks_to_live: Option<u32>, ascending: bool) -> (bool, i64) {
+    match ticks_to_live {
+        Some(ttl) if ascending => (false, i64::from(ttl)),
+        Some(ttl) => (false, -i64::from(ttl)),
+        None => (true, 0),
+    }
+}
+
+/// Movement speed for [`Creep::ticks_per_step`], with each body part given as
+/// its type, hits, and boost.
+fn ticks_per_step_for_body(
+    body: impl Iterator<Item = (Part, u32, Option<ResourceType>)>,
+    carried: u32,
+    terrain_cost: u32,
+) -> u32 {
+    let mut uncounted_carry = carried;
+    let mut weight = 0;
+    let mut fatigue_reduction = 0;
+    for (part, hits, boost) in body {
+        match part {
+            Part::Move if hits > 0 => {
+                let multiplier = match boost.and_then(ResourceType::boost) {
+                    Some(Boost::Move(multiplier)) => multiplier,
+                    _ => 1,
+                };
+                fatigue_reduction += MOVE_POWER * multiplier;
+            }
+            Part::Move => {}
+            Part::Carry => {
+                if uncounted_carry > 0 {
+                    weight += 1;
+                    if hits > 0 {
+                        let multiplier = match boost.and_then(ResourceType::boost) {
+                            Some(Boost::Carry(multiplier)) => multiplier,
+                            _ => 1,
+                        };
+                        uncounted_carry =
+                            uncounted_carry.saturating_sub(CARRY_CAPACITY * multiplier);
+                    }
+                }
+            }
+            _ => weight += 1,
+        }
+    }
+
+    let fatigue = weight * terrain_cost;
+    if fatigue == 0 {
+        1
+    } else if fatigue_reduction == 0 {
+        u32::MAX
+    } else {
+        fatigue.div_ceil(fatigue_reduction)
+    }
+}
+
+/// The reason [`Creep::deliver_energy`] didn't transfer energy.
+#[derive(Clone, Debug)]
+pub enum DeliverEnergyError {
+    /// None of your spawns, extensions, or towers in the room have free
+    /// capacity for energy.
+    NoTarget,
+    /// The transfer to the closest structure needing energy failed. With
+    /// [`ErrorCode::NotInRange`], the creep needs to move toward it.
+    Transfer(ErrorCode, StructureObject),
+}
+
+/// Picks the closest candidate, by range, which has free capacity remaining.
+fn closest_refill_target<T>(
+    origin: Position,
+    candidates: impl Iterator<Item = (Position, i32, T)>,
+) -> Option<T> {
+    candidates
+        .filter(|&(_, free_capacity, _)| free_capacity > 0)
+        .min_by_key(|&(pos, _, _)| origin.get_range_to(pos))
+        .map(|(_, _, candidate)| candidate)
+}
+
 impl JsCollectionFromValue for Creep {
     fn from_value(val: JsValue) -> Self {
         val.unchecked_into()
@@ -574,10 +940,14 @@ impl SharedCreepProperties for Creep {
         self.owner()
     }
 
-    fn saying(&self) -> Option<JsString> {
+    fn saying(&self) -> Option<String> {
         self.saying()
     }
 
+    fn saying_jsstring(&self) -> Option<JsString> {
+        self.saying_jsstring()
+    }
+
     fn ticks_to_live(&self) -> Option<u32> {
         self.ticks_to_live()
     }
@@ -626,10 +996,6 @@ impl SharedCreepProperties for Creep {
         }
     }
 
-    fn notify_when_attacked(&self, enabled: bool) -> Result<(), ErrorCode> {
-        self.notify_when_attacked(enabled)
-    }
-
     fn pickup(&self, target: &Resource) -> Result<(), ErrorCode> {
         self.pickup(target)
     }
@@ -667,6 +1033,12 @@ impl SharedCreepProperties for Creep {
     }
 }
 
+impl NotifyWhenAttacked for Creep {
+    fn notify_when_attacked(&self, enabled: bool) -> Result<(), ErrorCode> {
+        self.notify_when_attacked(enabled)
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     /// A [`BodyPart`] of a creep.
@@ -684,3 +1056,167 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn hits(this: &BodyPart) -> u32;
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        closest_refill_target, ticks_per_step_for_body, ttl_sort_key, BuildTracker, StepIntent,
+    };
+    use crate::{
+        constants::{movement_cost, Direction, Part, ResourceType, Terrain},
+        local::{Position, RawObjectId},
+    };
+
+    #[test]
+    fn ttl_ordering() {
+        let mut ttls = vec![Some(300), None, Some(1_499), Some(12), None, Some(700)];
+
+        ttls.sort_by_key(|&ttl| ttl_sort_key(ttl, true));
+        assert_eq!(
+            ttls,
+            vec![Some(12), Some(300), Some(700), Some(1_499), None, None]
+        );
+
+        // spawning creeps are last in both orders
+        ttls.sort_by_key(|&ttl| ttl_sort_key(ttl, false));
+        assert_eq!(
+            ttls,
+            vec![Some(1_499), Some(700), Some(300), Some(12), None, None]
+        );
+    }
+
+    #[test]
+    fn refill_target_selection() {
+        let origin = Position::from_world_coords(25, 25);
+
+        // the closest structure is full, so the next closest is picked
+        let candidates = vec![
+            (origin + (1, 0), 0, "full spawn"),
+            (origin + (-3, 2), 50, "extension"),
+            (origin + (8, 8), 500, "tower"),
+        ];
+        assert_eq!(
+            closest_refill_target(origin, candidates.into_iter()),
+            Some("extension")
+        );
+
+        let candidates = vec![(origin + (1, 0), 0, "full spawn")];
+        assert_eq!(closest_refill_target(origin, candidates.into_iter()), None);
+    }
+
+    #[test]
+    fn step_between_positions() {
+        let origin = Position::from_world_coords(25, 25);
+
+        assert_eq!(StepIntent::between(origin, origin), StepIntent::Arrived);
+
+        // adjacent targets use `move` with the direction of the target
+        assert_eq!(
+            StepIntent::between(origin, origin + (0, -1)),
+            StepIntent::Adjacent(Direction::Top)
+        );
+        assert_eq!(
+            StepIntent::between(origin, origin + (1, 1)),
+            StepIntent::Adjacent(Direction::BottomRight)
+        );
+        assert_eq!(
+            StepIntent::between(origin, origin + (-1, 0)),
+            StepIntent::Adjacent(Direction::Left)
+        );
+
+        // distant targets use `moveTo`
+        assert_eq!(
+            StepIntent::between(origin, origin + (2, 0)),
+            StepIntent::Distant
+        );
+        assert_eq!(
+            StepIntent::between(origin, origin + (10, -7)),
+            StepIntent::Distant
+        );
+
+        // positions in range 1 across a room edge aren't adjacent for `move`
+        let edge = Position::from_world_coords(49, 25);
+        assert_eq!(
+            StepIntent::between(edge, edge + (1, 0)),
+            StepIntent::Distant
+        );
+    }
+
+    #[test]
+    fn ticks_per_step_for_bodies() {
+        use Part::*;
+
+        let plain = movement_cost(Terrain::Plain, false).unwrap();
+        let swamp = movement_cost(Terrain::Swamp, false).unwrap();
+        let road = movement_cost(Terrain::Plain, true).unwrap();
+        let ticks = |body: &[Part], carried: u32, terrain_cost: u32| {
+            let body = body.iter().map(|&part| (part, 100, None));
+            ticks_per_step_for_body(body, carried, terrain_cost)
+        };
+
+        // one move per other part moves every tick on plains, but not swamps
+        let worker = [Work, Carry, Move, Move];
+        assert_eq!(ticks(&worker, 50, plain), 1);
+        assert_eq!(ticks(&worker, 50, swamp), 5);
+        // empty carry parts don't weigh anything
+        assert_eq!(ticks(&worker, 0, swamp), 3);
+
+        // one move per two other parts only keeps full speed on roads
+        let hauler = [Carry, Carry, Move];
+        assert_eq!(ticks(&hauler, 100, road), 1);
+        assert_eq!(ticks(&hauler, 100, plain), 2);
+        // only the first carry part is needed for 50 resources
+        assert_eq!(ticks(&hauler, 50, plain), 1);
+        assert_eq!(ticks(&hauler, 0, swamp), 1);
+
+        // creeps of only move parts never get fatigued
+        assert_eq!(ticks(&[Move, Move], 0, swamp), 1);
+
+        // creeps with no move parts can't move on their own
+        assert_eq!(ticks(&[Work, Work, Work], 0, plain), u32::MAX);
+        assert_eq!(ticks(&[Carry], 0, plain), 1);
+
+        // damaged move parts don't reduce fatigue, and boosted ones reduce more
+        let body = [
+            (Work, 100, None),
+            (Work, 100, None),
+            (Move, 0, None),
+            (Move, 100, Some(ResourceType::CatalyzedZynthiumAlkalide)),
+        ];
+        assert_eq!(ticks_per_step_for_body(body.into_iter(), 0, swamp), 3);
+
+        // a boosted carry part holds more before the next one counts
+        let body = [
+            (Carry, 100, Some(ResourceType::KeaniumHydride)),
+            (Carry, 100, None),
+            (Move, 100, None),
+        ];
+        assert_eq!(ticks_per_step_for_body(body.into_iter(), 100, plain), 1);
+        assert_eq!(ticks_per_step_for_body(body.into_iter(), 101, plain), 2);
+    }
+
+    #[test]
+    fn build_progress_across_ticks() {
+        let site: RawObjectId = "5bbcab8a9099fc012e633c3d".parse().unwrap();
+        let other_site: RawObjectId = "5bbcab8a9099fc012e633c3e".parse().unwrap();
+        let mut tracker = BuildTracker::default();
+        let mut record = |tick, creep: &str, site, progress| {
+            tracker.record(tick, creep.to_owned(), site, progress)
+        };
+
+        // nothing to compare against on the first tick
+        assert_eq!(record(100, "builder", site, 0), None);
+        // 5 WORK parts build 25 progress per tick, applied at the end of the
+        // tick so that it's seen on the next one
+        assert_eq!(record(101, "builder", site, 25), Some(25));
+        assert_eq!(record(101, "helper", site, 25), None);
+        assert_eq!(record(102, "builder", site, 75), Some(50));
+        assert_eq!(record(102, "helper", site, 75), Some(50));
+
+        // switching sites, or skipping a tick, starts over
+        assert_eq!(record(103, "builder", other_site, 0), None);
+        assert_eq!(record(105, "builder", other_site, 50), None);
+        assert_eq!(record(106, "builder", other_site, 50), Some(0));
+        assert_eq!(record(107, "helper", site, 100), None);
+    }
+}
@@ -2,6 +2,7 @@ use js_sys::Object;
 use wasm_bindgen::{prelude::*, JsCast};
 
 use crate::{
+    constants::ResourceType,
     local::RoomName,
     objects::{CostMatrix, FindPathOptions, PolyStyle},
     pathfinder::SingleRoomCostResult,
@@ -222,3 +223,88 @@ where
         })
     }
 }
+
+/// A resource and amount to move with [`SharedCreepProperties::transfer_resource`],
+/// [`SharedCreepProperties::withdraw_resource`], or
+/// [`SharedCreepProperties::drop_resource`].
+///
+/// Without an amount, as much of the resource as possible is moved.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{ResourceMove, ResourceType};
+///
+/// let all_energy = ResourceMove::new(ResourceType::Energy);
+/// let some_keanium = ResourceMove::new(ResourceType::Keanium).amount(50);
+///
+/// assert_eq!(all_energy.amount, None);
+/// assert_eq!(some_keanium.amount, Some(50));
+/// ```
+///
+/// [`SharedCreepProperties::transfer_resource`]: crate::traits::SharedCreepProperties::transfer_resource
+/// [`SharedCreepProperties::withdraw_resource`]: crate::traits::SharedCreepProperties::withdraw_resource
+/// [`SharedCreepProperties::drop_resource`]: crate::traits::SharedCreepProperties::drop_resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResourceMove {
+    /// The resource to move.
+    pub resource: ResourceType,
+    /// The amount to move, or `None` to move as much as possible.
+    pub amount: Option<u32>,
+}
+
+impl ResourceMove {
+    /// Moves as much of the resource as possible.
+    pub fn new(resource: ResourceType) -> Self {
+        ResourceMove {
+            resource,
+            amount: None,
+        }
+    }
+
+    /// Moves only the given amount of the resource.
+    pub fn amount(mut self, amount: u32) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+}
+
+impl From<ResourceType> for ResourceMove {
+    fn from(resource: ResourceType) -> Self {
+        ResourceMove::new(resource)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ResourceMove;
+    use crate::constants::ResourceType;
+
+    #[test]
+    fn resource_move_builder() {
+        assert_eq!(
+            ResourceMove::new(ResourceType::Energy),
+            ResourceMove {
+                resource: ResourceType::Energy,
+                amount: None,
+            }
+        );
+        assert_eq!(
+            ResourceMove::from(ResourceType::Energy),
+            ResourceMove::new(ResourceType::Energy)
+        );
+
+        let ghodium = ResourceMove::new(ResourceType::Ghodium).amount(1000);
+        assert_eq!(ghodium.resource, ResourceType::Ghodium);
+        assert_eq!(ghodium.amount, Some(1000));
+
+        // the last amount set wins
+        assert_eq!(
+            ResourceMove::new(ResourceType::Ghodium)
+                .amount(10)
+                .amount(5)
+                .amount,
+            Some(5)
+        );
+    }
+}
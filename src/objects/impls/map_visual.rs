@@ -433,16 +433,176 @@ impl MapVisual {
         Self::draw(&MapVisualShape::line(from, to, style));
     }
 
+    /// Draw a rectangle on the world map, with its top left corner at
+    /// `top_left` and its size given in tiles.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Game.map-visual.rect)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{MapVisual, Position, RectStyle, RoomCoordinate, RoomName};
+    ///
+    /// let room: RoomName = "W1N1".parse().unwrap();
+    /// let top_left = Position::new(
+    ///     RoomCoordinate::new(20).unwrap(),
+    ///     RoomCoordinate::new(20).unwrap(),
+    ///     room,
+    /// );
+    ///
+    /// // outline an 11x11 base footprint
+    /// MapVisual::rect(top_left, 11, 11, RectStyle::default().stroke("#ffffff"));
+    /// ```
     pub fn rect(top_left: Position, width: u32, height: u32, style: RectStyle) {
         Self::draw(&MapVisualShape::rect(top_left, width, height, style));
     }
 
+    /// Draw a polyline on the world map through the given points, which may be
+    /// in different rooms.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Game.map-visual.poly)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{MapVisual, PolyStyle, Position};
+    ///
+    /// let corners = [(1000, 1000), (1010, 1000), (1010, 1010), (1000, 1010), (1000, 1000)];
+    /// let points = corners
+    ///     .iter()
+    ///     .map(|&(x, y)| Position::from_world_coords(x, y))
+    ///     .collect();
+    ///
+    /// MapVisual::poly(points, PolyStyle::default().fill("#00ff00").opacity(0.2));
+    /// ```
     pub fn poly(points: Vec<Position>, style: PolyStyle) {
         let points = points.iter().map(Into::into).collect();
         Self::draw(&MapVisualShape::poly(points, style));
     }
 
-    pub fn text(pos: Position, text: String, style: MapTextStyle) {
-        Self::draw(&MapVisualShape::text(pos, text, style));
+    /// Draw text on the world map, centered on `pos` unless the style sets
+    /// another alignment.
+    ///
+    /// The map is much larger than a room, so the [`MapTextStyle::font_size`]
+    /// of map text is given in game coordinates rather than the room-relative
+    /// units of [`RoomVisual::text`]; a size of `50` is as tall as a room.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Game.map-visual.text)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{MapTextStyle, MapVisual, Position, RoomCoordinate, RoomName};
+    ///
+    /// let room: RoomName = "W1N1".parse().unwrap();
+    /// let center = Position::new(
+    ///     RoomCoordinate::new(25).unwrap(),
+    ///     RoomCoordinate::new(25).unwrap(),
+    ///     room,
+    /// );
+    ///
+    /// // label the room with text a fifth of its height
+    /// MapVisual::text(
+    ///     center,
+    ///     "claim",
+    ///     MapTextStyle::default().font_size(10.).color("#ffff00"),
+    /// );
+    /// ```
+    ///
+    /// [`RoomVisual::text`]: crate::objects::visual::RoomVisual::text
+    pub fn text(pos: Position, text: impl Into<String>, style: MapTextStyle) {
+        Self::draw(&MapVisualShape::text(pos, text.into(), style));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MapFontStyle, MapTextStyle, MapVisualShape};
+    use crate::{
+        local::{Position, RoomCoordinate},
+        objects::{LineDrawStyle, PolyStyle, RectStyle},
+        TextAlign,
+    };
+
+    fn pos(x: u8, y: u8, room: &str) -> Position {
+        Position::new(
+            RoomCoordinate::new(x).unwrap(),
+            RoomCoordinate::new(y).unwrap(),
+            room.parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn rect_serialization() {
+        let style = RectStyle::default().fill("#ff0000").opacity(0.5);
+        let shape = MapVisualShape::rect(pos(20, 21, "W1N1"), 11, 12, style);
+
+        assert_eq!(
+            serde_json::to_value(shape).unwrap(),
+            serde_json::json!({
+                "t": "r",
+                "x": 20,
+                "y": 21,
+                "n": "W1N1",
+                "w": 11,
+                "h": 12,
+                "s": { "fill": "#ff0000", "opacity": 0.5 },
+            })
+        );
+    }
+
+    #[test]
+    fn poly_serialization() {
+        let style = PolyStyle::default()
+            .stroke("#ffffff")
+            .stroke_width(2.)
+            .line_style(LineDrawStyle::Dashed);
+        let points = [pos(49, 10, "W1N1"), pos(0, 10, "W0N1")];
+        let shape = MapVisualShape::poly(points.iter().map(Into::into).collect(), style);
+
+        assert_eq!(
+            serde_json::to_value(shape).unwrap(),
+            serde_json::json!({
+                "t": "p",
+                "points": [
+                    { "x": 49, "y": 10, "n": "W1N1" },
+                    { "x": 0, "y": 10, "n": "W0N1" },
+                ],
+                "s": { "stroke": "#ffffff", "strokeWidth": 2.0, "lineStyle": "dashed" },
+            })
+        );
+    }
+
+    #[test]
+    fn text_serialization() {
+        let style = MapTextStyle::default()
+            .font_size(12.5)
+            .font_style(MapFontStyle::Italic)
+            .background_color(Some("#000000"))
+            .align(TextAlign::Left);
+        let shape = MapVisualShape::text(pos(25, 25, "W1N1"), "hello".to_owned(), style);
+
+        assert_eq!(
+            serde_json::to_value(shape).unwrap(),
+            serde_json::json!({
+                "t": "t",
+                "text": "hello",
+                "x": 25,
+                "y": 25,
+                "n": "W1N1",
+                "s": {
+                    "fontSize": 12.5,
+                    "fontStyle": "italic",
+                    "backgroundColor": "#000000",
+                    "align": "left",
+                },
+            })
+        );
+
+        // styles left at their defaults aren't sent at all
+        assert_eq!(
+            serde_json::to_value(MapTextStyle::default()).unwrap(),
+            serde_json::json!({})
+        );
     }
 }
@@ -63,7 +63,10 @@ extern "C" {
     fn powers_internal(this: &PowerCreep) -> Object;
 
     #[wasm_bindgen(method, getter = saying)]
-    fn saying_internal(this: &PowerCreep) -> Option<JsString>;
+    fn saying_internal(this: &PowerCreep) -> Option<String>;
+
+    #[wasm_bindgen(method, getter = saying)]
+    fn saying_jsstring_internal(this: &PowerCreep) -> Option<JsString>;
 
     #[wasm_bindgen(method, getter = store)]
     fn store_internal(this: &PowerCreep) -> Store;
@@ -201,13 +204,24 @@ impl PowerCreep {
         self.powers_internal().into()
     }
 
-    /// What the power creep said last tick.
+    /// What the power creep said last tick as a [`String`], or `None` if it
+    /// didn't say anything. Messages from other players' power creeps are only
+    /// visible if they were said publicly.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#PowerCreep.saying)
-    pub fn saying(&self) -> Option<JsString> {
+    pub fn saying(&self) -> Option<String> {
         self.saying_internal()
     }
 
+    /// What the power creep said last tick as a [`JsString`], or `None` if it
+    /// didn't say anything. Messages from other players' power creeps are only
+    /// visible if they were said publicly.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#PowerCreep.saying)
+    pub fn saying_jsstring(&self) -> Option<JsString> {
+        self.saying_jsstring_internal()
+    }
+
     /// The [`Store`] of the power creep, which contains information about what
     /// resources it is it carrying.
     ///
@@ -375,10 +389,14 @@ impl SharedCreepProperties for PowerCreep {
         self.owner()
     }
 
-    fn saying(&self) -> Option<JsString> {
+    fn saying(&self) -> Option<String> {
         self.saying()
     }
 
+    fn saying_jsstring(&self) -> Option<JsString> {
+        self.saying_jsstring()
+    }
+
     fn ticks_to_live(&self) -> Option<u32> {
         self.ticks_to_live()
     }
@@ -427,10 +445,6 @@ impl SharedCreepProperties for PowerCreep {
         }
     }
 
-    fn notify_when_attacked(&self, enabled: bool) -> Result<(), ErrorCode> {
-        ErrorCode::result_from_i8(self.notify_when_attacked_internal(enabled))
-    }
-
     fn pickup(&self, target: &Resource) -> Result<(), ErrorCode> {
         ErrorCode::result_from_i8(self.pickup_internal(target))
     }
@@ -468,6 +482,12 @@ impl SharedCreepProperties for PowerCreep {
     }
 }
 
+impl NotifyWhenAttacked for PowerCreep {
+    fn notify_when_attacked(&self, enabled: bool) -> Result<(), ErrorCode> {
+        self.notify_when_attacked(enabled)
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     /// A [`PowerCreep`] unit that may or may not be spawned in the current
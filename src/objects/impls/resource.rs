@@ -1,7 +1,11 @@
 use js_sys::JsString;
 use wasm_bindgen::prelude::*;
 
-use crate::{constants::ResourceType, objects::RoomObject, prelude::*};
+use crate::{
+    constants::{ResourceType, ENERGY_DECAY},
+    objects::RoomObject,
+    prelude::*,
+};
 
 #[wasm_bindgen]
 extern "C" {
@@ -38,3 +42,41 @@ impl HasId for Resource {
         Self::id_internal(self)
     }
 }
+
+impl Lootable for Resource {
+    fn contents(&self) -> Vec<(ResourceType, u32)> {
+        vec![(self.resource_type(), self.amount())]
+    }
+
+    fn ticks_to_decay(&self) -> u32 {
+        ticks_until_decayed(self.amount())
+    }
+}
+
+/// The number of ticks until a dropped resource pile of the given amount is
+/// gone, losing [`ENERGY_DECAY`]ths of its amount, rounded up, each tick.
+fn ticks_until_decayed(mut amount: u32) -> u32 {
+    let mut ticks = 0;
+    while amount > 0 {
+        amount -= amount.div_ceil(ENERGY_DECAY);
+        ticks += 1;
+    }
+    ticks
+}
+
+#[cfg(test)]
+mod test {
+    use super::ticks_until_decayed;
+
+    #[test]
+    fn dropped_resource_decay() {
+        assert_eq!(ticks_until_decayed(0), 0);
+        assert_eq!(ticks_until_decayed(1), 1);
+        // piles up to 1000 lose 1 each tick
+        assert_eq!(ticks_until_decayed(1_000), 1_000);
+        // 1001 loses 2 on the first tick, leaving 999
+        assert_eq!(ticks_until_decayed(1_001), 1_000);
+        // 2000 loses 2 each tick until it's down to 1000
+        assert_eq!(ticks_until_decayed(2_000), 1_500);
+    }
+}
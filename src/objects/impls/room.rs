@@ -1,4 +1,4 @@
-use std::{convert::TryInto, fmt};
+use std::{cell::RefCell, collections::HashMap, convert::TryInto, fmt, rc::Rc};
 
 use js_sys::{Array, JsString, Object};
 use num_traits::*;
@@ -11,12 +11,15 @@ use wasm_bindgen::{prelude::*, JsCast};
 
 use crate::{
     constants::{
-        find::*, look::*, Color, Direction, ErrorCode, ExitDirection, PowerType, ResourceType,
-        StructureType,
+        find::{self, *},
+        look::*,
+        Color, Direction, ErrorCode, ExitDirection, PowerType, ResourceType, StructureType,
+        ENERGY_REGEN_TIME,
     },
-    local::{LodashFilter, RoomName},
+    enums::StructureObject,
+    local::{LocalRoomTerrain, LodashFilter, Position, RoomCoordinate, RoomName, RoomXY},
     objects::*,
-    pathfinder::RoomCostResult,
+    pathfinder::{self, MultiRoomCostResult, RoomCostResult, SearchGoal, SearchOptions},
     prelude::*,
 };
 
@@ -167,6 +170,16 @@ extern "C" {
         right_x: u8,
         as_array: bool,
     ) -> Option<Array>;
+
+    #[wasm_bindgen(final, method, js_name = lookForAtArea)]
+    fn look_for_at_area_grid_internal(
+        this: &Room,
+        ty: Look,
+        top_y: u8,
+        left_x: u8,
+        bottom_y: u8,
+        right_x: u8,
+    ) -> Option<Object>;
 }
 
 impl Room {
@@ -207,6 +220,22 @@ impl Room {
         RoomVisual::new(Some(self.name()))
     }
 
+    /// Gets the terrain of this room as a [`LocalRoomTerrain`], copied into
+    /// Rust memory.
+    ///
+    /// Terrain never changes, so the terrain of each room is only copied once
+    /// for the lifetime of the global, with later calls sharing the cached
+    /// copy.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.getTerrain)
+    pub fn raw_terrain(&self) -> Rc<LocalRoomTerrain> {
+        TERRAIN_CACHE.with(|cache| {
+            cached_terrain(&mut cache.borrow_mut(), self.name(), || {
+                self.get_terrain().into()
+            })
+        })
+    }
+
     /// Creates a construction site at given coordinates within this room. If
     /// it's a [`StructureSpawn`], a name can optionally be assigned for the
     /// structure.
@@ -271,6 +300,133 @@ impl Room {
             .collect()
     }
 
+    /// Find all hostile structures in the room, skipping those owned by any of
+    /// the given allied players.
+    ///
+    /// Usernames are compared exactly, as returned by [`Owner::username`].
+    /// Allied structures are filtered out by the `filter` option of the find
+    /// call, so they're never converted into Rust values.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.find)
+    pub fn hostile_structures(&self, ignore_allies: &[&str]) -> Vec<StructureObject> {
+        if ignore_allies.is_empty() {
+            return self.find(HOSTILE_STRUCTURES, None);
+        }
+
+        let allies: Vec<String> = ignore_allies.iter().map(|&ally| ally.to_owned()).collect();
+        let filter = Closure::wrap(Box::new(move |structure: OwnedStructure| -> bool {
+            let owner = structure.owner().map(|owner| owner.username());
+            !is_allied_owner(owner.as_deref(), &allies)
+        }) as Box<dyn FnMut(OwnedStructure) -> bool>);
+
+        let options: FindOptions = Object::new().unchecked_into();
+        options.filter(filter.as_ref().clone().unchecked_into());
+
+        self.find(HOSTILE_STRUCTURES, Some(&options))
+    }
+
+    /// Count your creeps in the room by the `role` field of their memory.
+    ///
+    /// Creeps without a string `role` aren't counted.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.find)
+    pub fn creep_counts_by_role(&self) -> HashMap<String, u32> {
+        count_roles(
+            self.find(find::MY_CREEPS, None)
+                .iter()
+                .map(|creep| creep.memory_role()),
+        )
+    }
+
+    /// Find the closest structure in the room, by range from a position, with
+    /// hits below a fraction of its maximum hits.
+    ///
+    /// Indestructible structures, which have no hits, are never chosen.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.find)
+    pub fn closest_repair_target(&self, from: Position, max_hits_ratio: f32) -> Option<Structure> {
+        let structures = self
+            .find(find::STRUCTURES, None)
+            .into_iter()
+            .map(|structure| {
+                let structure = structure.as_structure();
+                (
+                    structure.pos(),
+                    structure.hits(),
+                    structure.hits_max(),
+                    structure.clone(),
+                )
+            });
+        closest_below_hits_ratio(from, structures, max_hits_ratio)
+    }
+
+    /// Compare the energy income of the sources in this room to the given
+    /// consumption, returning the energy per tick left over if positive or the
+    /// shortfall if negative.
+    ///
+    /// Each source is assumed to be fully harvested, producing its
+    /// [`Source::energy_capacity`] once every [`ENERGY_REGEN_TIME`] ticks.
+    /// The income isn't rounded, so sources whose capacity isn't a multiple of
+    /// [`ENERGY_REGEN_TIME`], like the 4000 energy sources in source keeper
+    /// rooms, give a fractional balance.
+    ///
+    /// [`ENERGY_REGEN_TIME`]: crate::constants::ENERGY_REGEN_TIME
+    pub fn energy_balance(&self, consumption_per_tick: u32) -> f64 {
+        let capacities = self
+            .find(find::SOURCES, None)
+            .into_iter()
+            .map(|source| source.energy_capacity());
+        energy_balance(capacities, consumption_per_tick)
+    }
+
+    /// Find the target position closest to `from` by path, using a single
+    /// [`pathfinder::search_many`] call with every target as a goal.
+    ///
+    /// Like `Room.findClosestByPath`, the search ends next to a target rather
+    /// than on it, so targets can be on impassable tiles. The search stays in
+    /// this room: other rooms are treated as impassable and the options'
+    /// `max_rooms` is replaced with 1, so `from` should be in this room.
+    /// Returns `None` if the search couldn't reach any of the targets.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{find, game, pathfinder::SearchOptions, HasPosition};
+    ///
+    /// let creep = game::creeps().get("Bob".to_owned()).unwrap();
+    /// let room = creep.room().unwrap();
+    /// let sources: Vec<_> = room
+    ///     .find(find::SOURCES_ACTIVE, None)
+    ///     .iter()
+    ///     .map(|source| source.pos())
+    ///     .collect();
+    ///
+    /// let nearest = room.find_closest_by_path(creep.pos(), &sources, SearchOptions::default());
+    /// ```
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.findClosestByPath)
+    pub fn find_closest_by_path<F>(
+        &self,
+        from: Position,
+        targets: &[Position],
+        opts: SearchOptions<F>,
+    ) -> Option<Position>
+    where
+        F: FnMut(RoomName) -> MultiRoomCostResult,
+    {
+        if targets.is_empty() {
+            return None;
+        }
+
+        let goals = targets.iter().map(|&pos| SearchGoal::new(pos, 1));
+        let opts = opts.within_room(self.name());
+        let results = pathfinder::search_many(from, goals, Some(opts));
+        if results.incomplete() {
+            return None;
+        }
+        closest_reached(from, results.path().last().copied(), targets)
+    }
+
     /// Find an exit from the current room which leads to a target room.
     ///
     /// [Screeps documentation](https://docs.screeps.com/api/#Room.findExitTo)
@@ -404,6 +560,49 @@ impl Room {
             })
             .unwrap_or_default()
     }
+
+    /// Get all objects of a certain type in a certain area, each paired with
+    /// its coordinates within the room.
+    ///
+    /// The engine groups the objects found by row and column; this flattens
+    /// them, with rows from top to bottom and each row from left to right.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Room.lookForAtArea)
+    pub fn look_for_at_area_typed<T>(
+        &self,
+        _ty: T,
+        top_y: u8,
+        left_x: u8,
+        bottom_y: u8,
+        right_x: u8,
+    ) -> Vec<(RoomXY, T::Item)>
+    where
+        T: LookConstant,
+    {
+        let Some(grid) =
+            self.look_for_at_area_grid_internal(T::look_code(), top_y, left_x, bottom_y, right_x)
+        else {
+            return Vec::new();
+        };
+
+        let entries = |object: &JsValue| -> Vec<(String, JsValue)> {
+            Object::entries(object.unchecked_ref())
+                .iter()
+                .map(|entry| {
+                    let entry: Array = entry.unchecked_into();
+                    (entry.get(0).as_string().unwrap_or_default(), entry.get(1))
+                })
+                .collect()
+        };
+        let rows = entries(&grid).into_iter().map(|(y, row)| {
+            let cells = entries(&row).into_iter().map(|(x, cell)| {
+                let cell: Array = cell.unchecked_into();
+                (x, cell.iter().map(T::convert_and_check_item).collect())
+            });
+            (y, cells.collect::<Vec<_>>())
+        });
+        flatten_look_grid(rows)
+    }
 }
 
 impl PartialEq for Room {
@@ -420,6 +619,104 @@ impl JsCollectionFromValue for Room {
     }
 }
 
+/// Whether a structure with the given owner belongs to one of the allies;
+/// unowned structures never do.
+fn is_allied_owner<A: AsRef<str>>(owner: Option<&str>, allies: &[A]) -> bool {
+    owner.is_some_and(|owner| allies.iter().any(|ally| ally.as_ref() == owner))
+}
+
+/// Tallies the number of times each role appears.
+fn count_roles(roles: impl Iterator<Item = Option<String>>) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    for role in roles.flatten() {
+        *counts.entry(role).or_default() += 1;
+    }
+    counts
+}
+
+/// Picks the closest candidate, by range, with hits below the fraction of its
+/// maximum hits, given each candidate's position, hits, and maximum hits.
+fn closest_below_hits_ratio<T>(
+    from: Position,
+    candidates: impl Iterator<Item = (Position, u32, u32, T)>,
+    max_hits_ratio: f32,
+) -> Option<T> {
+    candidates
+        .filter(|&(_, hits, hits_max, _)| {
+            hits_max > 0 && (hits as f64) < max_hits_ratio as f64 * hits_max as f64
+        })
+        .min_by_key(|&(pos, ..)| from.get_range_to(pos))
+        .map(|(.., candidate)| candidate)
+}
+
+/// Picks the target a search from `from` reached, which is the first target
+/// next to the end of the path, or next to `from` for an empty path.
+fn closest_reached(
+    from: Position,
+    path_end: Option<Position>,
+    targets: &[Position],
+) -> Option<Position> {
+    let end = path_end.unwrap_or(from);
+    targets
+        .iter()
+        .copied()
+        .find(|&target| end.is_near_to(target))
+}
+
+/// The energy per tick produced by sources with the given capacities, minus
+/// the consumption.
+fn energy_balance(source_capacities: impl IntoIterator<Item = u32>, consumption: u32) -> f64 {
+    let total_capacity: u64 = source_capacities
+        .into_iter()
+        .map(|capacity| capacity as u64)
+        .sum();
+    total_capacity as f64 / ENERGY_REGEN_TIME as f64 - consumption as f64
+}
+
+thread_local! {
+    static TERRAIN_CACHE: RefCell<HashMap<RoomName, Rc<LocalRoomTerrain>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Gets the terrain cached for a room, fetching and caching it with `fetch` if
+/// there isn't any.
+fn cached_terrain(
+    cache: &mut HashMap<RoomName, Rc<LocalRoomTerrain>>,
+    room: RoomName,
+    fetch: impl FnOnce() -> LocalRoomTerrain,
+) -> Rc<LocalRoomTerrain> {
+    cache
+        .entry(room)
+        .or_insert_with(|| Rc::new(fetch()))
+        .clone()
+}
+
+/// Flattens the rows of a look result grid, each keyed by its `y` coordinate
+/// and holding cells keyed by their `x` coordinate, into positioned items.
+///
+/// Cells without any items don't appear in the result, and neither do rows or
+/// cells with keys which aren't valid room coordinates.
+fn flatten_look_grid<T, R>(rows: impl IntoIterator<Item = (String, R)>) -> Vec<(RoomXY, T)>
+where
+    R: IntoIterator<Item = (String, Vec<T>)>,
+{
+    let coord = |key: &str| key.parse().ok().and_then(|c| RoomCoordinate::new(c).ok());
+
+    let mut items = Vec::new();
+    for (y, cells) in rows {
+        let Some(y) = coord(&y) else {
+            continue;
+        };
+        for (x, cell) in cells {
+            let Some(x) = coord(&x) else {
+                continue;
+            };
+            items.extend(cell.into_iter().map(|item| (RoomXY { x, y }, item)));
+        }
+    }
+    items
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen]
@@ -977,3 +1274,216 @@ pub struct PowerEvent {
     pub target_id: String,
     pub power: PowerType,
 }
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, collections::HashMap, rc::Rc};
+
+    use super::{
+        cached_terrain, closest_below_hits_ratio, closest_reached, count_roles, energy_balance,
+        flatten_look_grid, is_allied_owner,
+    };
+    use crate::{
+        constants::Terrain,
+        local::{LocalRoomTerrain, Position, RoomName, RoomXY},
+    };
+
+    #[test]
+    fn closest_by_path_target() {
+        let room: RoomName = "W1N1".parse().unwrap();
+        let pos = |x: u8, y: u8| {
+            let xy = RoomXY::try_from((x, y)).unwrap();
+            Position::new(xy.x, xy.y, room)
+        };
+
+        // a wall between the start and the target at (10, 12) forces the
+        // search around to the target at (16, 10), which is further by range
+        let targets = [pos(10, 12), pos(16, 10)];
+        assert_eq!(
+            closest_reached(pos(10, 8), Some(pos(15, 9)), &targets),
+            Some(pos(16, 10))
+        );
+        assert_eq!(
+            closest_reached(pos(10, 8), Some(pos(11, 11)), &targets),
+            Some(pos(10, 12))
+        );
+
+        // already next to a target, so the path is empty
+        assert_eq!(
+            closest_reached(pos(17, 11), None, &targets),
+            Some(pos(16, 10))
+        );
+        assert_eq!(closest_reached(pos(30, 30), None, &targets), None);
+    }
+
+    #[test]
+    fn creep_roles_tallied() {
+        // the `role` of each creep in the room, if it has one
+        let roles = ["harvester", "upgrader", "harvester", "hauler", "harvester"]
+            .into_iter()
+            .map(|role| Some(role.to_owned()))
+            .chain([None, Some("upgrader".to_owned())]);
+
+        assert_eq!(
+            count_roles(roles),
+            HashMap::from([
+                ("harvester".to_owned(), 3),
+                ("upgrader".to_owned(), 2),
+                ("hauler".to_owned(), 1),
+            ])
+        );
+        assert!(count_roles([None, None].into_iter()).is_empty());
+    }
+
+    #[test]
+    fn closest_damaged_structure() {
+        let room: RoomName = "W1N1".parse().unwrap();
+        let pos = |x: u8, y: u8| {
+            let xy = RoomXY::try_from((x, y)).unwrap();
+            Position::new(xy.x, xy.y, room)
+        };
+        let from = pos(25, 25);
+
+        // a road at 80% hits, a wall at 0.1% of its 300M hits, and an
+        // undamaged container right next to the creep
+        let structures = || {
+            [
+                (pos(30, 25), 4_000, 5_000, "road"),
+                (pos(20, 20), 300_000, 300_000_000, "wall"),
+                (pos(26, 25), 250_000, 250_000, "container"),
+                // a controller has no hits
+                (pos(25, 24), 0, 0, "controller"),
+            ]
+            .into_iter()
+        };
+
+        assert_eq!(
+            closest_below_hits_ratio(from, structures(), 0.9),
+            Some("road")
+        );
+        assert_eq!(
+            closest_below_hits_ratio(from, structures(), 0.5),
+            Some("wall")
+        );
+        assert_eq!(closest_below_hits_ratio(from, structures(), 0.0005), None);
+        assert_eq!(
+            closest_below_hits_ratio(from, structures(), 1.5),
+            Some("container")
+        );
+    }
+
+    #[test]
+    fn allied_owner_filtering() {
+        let allies = ["Ally", "Friend"];
+
+        // an invader tower and an ally's spawn
+        assert!(!is_allied_owner(Some("Invader"), &allies));
+        assert!(is_allied_owner(Some("Ally"), &allies));
+        // names are compared exactly
+        assert!(!is_allied_owner(Some("ally"), &allies));
+        assert!(!is_allied_owner::<&str>(Some("Ally"), &[]));
+        assert!(!is_allied_owner(None, &allies));
+        // owned names, as captured by the find filter, work the same
+        assert!(is_allied_owner(Some("Friend"), &["Friend".to_owned()]));
+    }
+
+    #[test]
+    fn energy_balance_two_sources() {
+        // two owned sources regenerate 3000 energy each every 300 ticks
+        let sources = [3000, 3000];
+        assert_eq!(energy_balance(sources, 0), 20.);
+        // e.g. a 15 WORK upgrader
+        assert_eq!(energy_balance(sources, 15), 5.);
+        assert_eq!(energy_balance(sources, 20), 0.);
+        assert_eq!(energy_balance(sources, 32), -12.);
+
+        // an unreserved room's sources only have 1500 energy
+        assert_eq!(energy_balance([1500, 1500], 15), -5.);
+        assert_eq!(energy_balance([], 3), -3.);
+
+        // source keeper sources give 13 1/3 energy per tick each
+        let keeper = energy_balance([4000, 4000, 4000], 30);
+        assert!((keeper - 10.).abs() < 1e-9);
+        let keeper = energy_balance([4000], 0);
+        assert!((keeper - 40. / 3.).abs() < 1e-9);
+
+        // consumption beyond i32::MAX doesn't wrap around
+        assert!(energy_balance(sources, u32::MAX) < 0.);
+        assert_eq!(
+            energy_balance([u32::MAX; 2], 0),
+            2. * u32::MAX as f64 / 300.
+        );
+    }
+
+    #[test]
+    fn look_grid_flattening() {
+        let xy = |x: u8, y: u8| RoomXY::try_from((x, y)).unwrap();
+        let cell = |x: &str, items: &[&'static str]| (x.to_owned(), items.to_vec());
+
+        // shape of `room.lookForAtArea(LOOK_STRUCTURES, 10, 20, 11, 22)`
+        let rows = vec![
+            (
+                "10".to_owned(),
+                vec![
+                    cell("20", &["road"]),
+                    cell("21", &[]),
+                    cell("22", &["rampart", "spawn"]),
+                ],
+            ),
+            (
+                "11".to_owned(),
+                vec![cell("20", &[]), cell("21", &["container"]), cell("22", &[])],
+            ),
+        ];
+        assert_eq!(
+            flatten_look_grid(rows),
+            vec![
+                (xy(20, 10), "road"),
+                (xy(22, 10), "rampart"),
+                (xy(22, 10), "spawn"),
+                (xy(21, 11), "container"),
+            ]
+        );
+
+        // keys which aren't room coordinates are skipped
+        let rows = vec![
+            ("50".to_owned(), vec![cell("0", &["road"])]),
+            (
+                "0".to_owned(),
+                vec![cell("-1", &["road"]), cell("x", &["road"])],
+            ),
+            ("49".to_owned(), vec![cell("49", &["wall"])]),
+        ];
+        assert_eq!(flatten_look_grid(rows), vec![(xy(49, 49), "wall")]);
+    }
+
+    #[test]
+    fn terrain_cached_per_room() {
+        let w1n1: RoomName = "W1N1".parse().unwrap();
+        let w2n1: RoomName = "W2N1".parse().unwrap();
+        let fetches = Cell::new(0);
+        // stands in for copying the terrain of a room from `Room.getTerrain()`,
+        // with a wall at (0, 0) in the second copy and plains otherwise
+        let fetch_terrain = || {
+            fetches.set(fetches.get() + 1);
+            let mut bits = Box::new([0; 2500]);
+            bits[0] = if fetches.get() == 2 { 1 } else { 0 };
+            LocalRoomTerrain::new_from_bits(bits)
+        };
+        let first_tile =
+            |terrain: &LocalRoomTerrain| terrain.get(RoomXY::try_from((0, 0)).unwrap());
+
+        let mut cache = HashMap::new();
+        let terrain = cached_terrain(&mut cache, w1n1, fetch_terrain);
+        assert_eq!(first_tile(&terrain), Terrain::Plain);
+        // later calls share the same copy instead of fetching again
+        let again = cached_terrain(&mut cache, w1n1, fetch_terrain);
+        assert!(Rc::ptr_eq(&terrain, &again));
+        assert_eq!(fetches.get(), 1);
+
+        // each room is cached separately
+        let terrain = cached_terrain(&mut cache, w2n1, fetch_terrain);
+        assert_eq!(first_tile(&terrain), Terrain::Wall);
+        assert_eq!(fetches.get(), 2);
+    }
+}
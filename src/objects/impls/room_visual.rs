@@ -1,7 +1,10 @@
 use js_sys::JsString;
 use serde::Serialize;
 
-use crate::local::RoomName;
+use crate::{
+    constants::ResourceType,
+    local::{RoomName, RoomXY},
+};
 
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -410,4 +413,82 @@ impl RoomVisual {
     pub fn text(&self, x: f32, y: f32, text: String, style: Option<TextStyle>) {
         self.draw(&Visual::text(x, y, text, style));
     }
+
+    /// Draw an icon for a resource centered on a tile, with `size` as the
+    /// icon's radius, defaulting to 0.25.
+    ///
+    /// The game has no built-in resource icon visual, so the icon is drawn as
+    /// a circle, colored for energy and power and labeled with the resource's
+    /// name for all other resources.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{constants::ResourceType, game, RoomXY};
+    ///
+    /// let room = game::rooms().get("W1N1".parse().unwrap()).unwrap();
+    /// let storage = RoomXY::try_from((25, 25)).unwrap();
+    ///
+    /// room.visual().resource(ResourceType::Energy, storage, None);
+    /// room.visual().resource(ResourceType::Catalyst, storage, Some(0.4));
+    /// ```
+    pub fn resource(&self, resource: ResourceType, xy: RoomXY, size: Option<f32>) {
+        self.draw_multi(&resource_icon(resource, xy, size.unwrap_or(0.25)));
+    }
+}
+
+/// The visuals drawn by [`RoomVisual::resource`].
+fn resource_icon(resource: ResourceType, xy: RoomXY, size: f32) -> Vec<Visual> {
+    let (x, y) = (f32::from(xy.x.u8()), f32::from(xy.y.u8()));
+    let circle = |fill: &str| {
+        Visual::circle(
+            x,
+            y,
+            Some(CircleStyle::default().radius(size).fill(fill).opacity(1.)),
+        )
+    };
+
+    match resource {
+        ResourceType::Energy => vec![circle("#ffe56d")],
+        ResourceType::Power => vec![circle("#ff1930")],
+        _ => {
+            // text is drawn from its baseline, so shift it down to center it
+            let font = size * 0.8;
+            let label = TextStyle::default().color("#ffffff").font(font).opacity(1.);
+            vec![
+                circle("#333333"),
+                Visual::text(x, y + font * 0.35, resource.to_string(), Some(label)),
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::resource_icon;
+    use crate::{constants::ResourceType, local::RoomXY};
+
+    #[test]
+    fn resource_icon_visuals() {
+        let xy = RoomXY::try_from((10, 20)).unwrap();
+
+        let energy = serde_json::to_value(resource_icon(ResourceType::Energy, xy, 0.25)).unwrap();
+        assert_eq!(
+            energy,
+            serde_json::json!([{
+                "t": "c",
+                "x": 10.0,
+                "y": 20.0,
+                "s": { "radius": 0.25, "fill": "#ffe56d", "opacity": 1.0 },
+            }])
+        );
+
+        let catalyst =
+            serde_json::to_value(resource_icon(ResourceType::Catalyst, xy, 0.5)).unwrap();
+        assert_eq!(catalyst[0]["s"]["fill"], "#333333");
+        assert_eq!(catalyst[1]["t"], "t");
+        assert_eq!(catalyst[1]["text"], "X");
+        let font = catalyst[1]["s"]["font"].as_f64().unwrap();
+        assert!((font - 0.4).abs() < 1e-6);
+    }
 }
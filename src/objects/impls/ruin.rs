@@ -2,6 +2,7 @@ use js_sys::JsString;
 use wasm_bindgen::prelude::*;
 
 use crate::{
+    constants::ResourceType,
     objects::{RoomObject, Store, Structure},
     prelude::*,
 };
@@ -64,6 +65,16 @@ impl HasId for Ruin {
     }
 }
 
+impl Lootable for Ruin {
+    fn contents(&self) -> Vec<(ResourceType, u32)> {
+        Self::store(self).iter().collect()
+    }
+
+    fn ticks_to_decay(&self) -> u32 {
+        Self::ticks_to_decay(self)
+    }
+}
+
 impl HasStore for Ruin {
     fn store(&self) -> Store {
         Self::store(self)
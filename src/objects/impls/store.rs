@@ -1,4 +1,6 @@
-use js_sys::Object;
+use std::collections::HashMap;
+
+use js_sys::{Array, Object};
 use wasm_bindgen::{prelude::*, JsCast};
 
 use crate::constants::ResourceType;
@@ -41,21 +43,253 @@ extern "C" {
 
 impl Store {
     pub fn store_types(&self) -> Vec<ResourceType> {
-        Object::keys(self.unchecked_ref())
+        Object::keys::<JsValue>(self.unchecked_ref())
             .iter()
             .filter_map(|v| ResourceType::from_js_value(&v))
             .collect()
     }
 
-    pub fn get_capacity(&self, ty: Option<ResourceType>) -> u32 {
-        self.get_capacity_internal(ty).unwrap_or(0)
+    /// Get an iterator over the resources in the [`Store`] and the amount of
+    /// each, reading the store's contents in a single call.
+    ///
+    /// Only resources with a nonzero amount are included, so an empty store
+    /// gives an empty iterator.
+    pub fn iter(&self) -> impl Iterator<Item = (ResourceType, u32)> {
+        let array = Object::entries(self.unchecked_ref());
+        let entries = (0..array.length()).filter_map(move |idx| {
+            let entry: Array = array.get(idx).unchecked_into();
+            let ty = ResourceType::from_js_value(&entry.get(0))?;
+            Some((ty, entry.get(1).as_f64()?))
+        });
+
+        nonzero_resources(entries)
+    }
+
+    /// Get a snapshot of the contents of the [`Store`], mapping each resource
+    /// it contains to its amount.
+    ///
+    /// The map can be serialized into memory, and compared with a later
+    /// snapshot using [`store_diff`].
+    ///
+    /// [`store_diff`]: crate::local::store_diff
+    pub fn to_hash_map(&self) -> HashMap<ResourceType, u32> {
+        self.iter().collect()
+    }
+
+    /// Get the capacity of the [`Store`] for the specified resource, or the
+    /// general capacity of the store when passing `None`.
+    ///
+    /// Returns `None` when the store can't hold the resource. Stores which can
+    /// only hold specific resources, like those of labs and nukers, have no
+    /// general capacity, so they always return `None` when passed `None`; see
+    /// [`Store::is_restricted`]. This is distinct from `Some(0)`, which is the
+    /// capacity of a store that accepts the resource but has no room, like a
+    /// creep without carry parts.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Store.getCapacity)
+    pub fn get_capacity(&self, ty: Option<ResourceType>) -> Option<u32> {
+        self.get_capacity_internal(ty)
+    }
+
+    /// Get the free capacity of the [`Store`] for the specified resource, or
+    /// the general free capacity of the store when passing `None`.
+    ///
+    /// Returns `None` in the same cases as [`Store::get_capacity`], where the
+    /// game would otherwise report a free capacity of 0.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Store.getFreeCapacity)
+    pub fn get_free_capacity(&self, ty: Option<ResourceType>) -> Option<i32> {
+        free_capacity(
+            self.get_capacity_internal(ty),
+            self.get_free_capacity_internal(ty),
+        )
+    }
+
+    /// Get the amount of the specified resource in the [`Store`], or the total
+    /// amount of all resources when passing `None`.
+    ///
+    /// Returns `None` when passing `None` to a restricted store, which has no
+    /// general capacity to count against; see [`Store::is_restricted`].
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Store.getUsedCapacity)
+    pub fn get_used_capacity(&self, ty: Option<ResourceType>) -> Option<u32> {
+        self.get_used_capacity_internal(ty)
+    }
+
+    /// Whether the [`Store`] can only hold specific resources, each with its
+    /// own capacity, rather than having a general capacity shared by any
+    /// resource.
+    ///
+    /// Lab, nuker, power spawn, spawn, extension, link, and tower stores are
+    /// restricted, while creep, container, storage, terminal, and factory
+    /// stores are not.
+    pub fn is_restricted(&self) -> bool {
+        self.get_capacity_internal(None).is_none()
+    }
+
+    /// Whether the [`Store`] has enough free capacity to accept all of the
+    /// given amounts of resources at once.
+    ///
+    /// For a store with a general capacity, the amounts must fit in its total
+    /// free capacity together. For a restricted store, each amount must fit in
+    /// the free capacity for its resource, so any resource the store can't
+    /// hold makes this `false`; see [`Store::is_restricted`].
+    ///
+    /// Restricted stores hold at most one resource other than energy. An empty
+    /// lab reports free capacity for every mineral, but can only be filled
+    /// with one of them, so more than one non-energy resource is never
+    /// accepted by a restricted store.
+    pub fn can_accept_all(&self, resources: &HashMap<ResourceType, u32>) -> bool {
+        accepts_all(resources, self.get_free_capacity(None), |ty| {
+            self.get_free_capacity(Some(ty))
+        })
+    }
+}
+
+/// The free capacity for [`Store::get_free_capacity`], given the capacity for
+/// the same resource and the free capacity the game reported.
+///
+/// The game computes the free capacity by subtracting two values that can be
+/// `null`, which JavaScript coerces to a free capacity of 0.
+fn free_capacity(capacity: Option<u32>, reported_free: Option<i32>) -> Option<i32> {
+    capacity.and(reported_free)
+}
+
+/// Whether the amounts fit in a store for [`Store::can_accept_all`], given the
+/// store's general free capacity, or `None` if it's restricted, and its free
+/// capacity for each resource.
+fn accepts_all(
+    resources: &HashMap<ResourceType, u32>,
+    general_free: Option<i32>,
+    free_for: impl Fn(ResourceType) -> Option<i32>,
+) -> bool {
+    let fits = |amount: u64, free: Option<i32>| amount <= free.unwrap_or(0).max(0) as u64;
+    match general_free {
+        Some(free) => fits(
+            resources.values().map(|&amount| u64::from(amount)).sum(),
+            Some(free),
+        ),
+        None => {
+            let mut nonzero = resources.iter().filter(|&(_, &amount)| amount > 0);
+            let non_energy = nonzero
+                .clone()
+                .filter(|&(&ty, _)| ty != ResourceType::Energy)
+                .count();
+            non_energy <= 1 && nonzero.all(|(&ty, &amount)| fits(u64::from(amount), free_for(ty)))
+        }
     }
+}
+
+/// Converts the amounts of each resource in a store, skipping resources with
+/// no amount.
+fn nonzero_resources(
+    entries: impl Iterator<Item = (ResourceType, f64)>,
+) -> impl Iterator<Item = (ResourceType, u32)> {
+    entries.filter_map(|(ty, amount)| {
+        let amount = amount as u32;
+        (amount > 0).then_some((ty, amount))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{accepts_all, free_capacity, nonzero_resources};
+    use crate::constants::ResourceType;
+
+    #[test]
+    fn store_entries() {
+        // entries of a store object like `{ energy: 150, U: 25, H: 0 }`
+        let entries = vec![
+            (ResourceType::Energy, 150.),
+            (ResourceType::Utrium, 25.),
+            (ResourceType::Hydrogen, 0.),
+        ];
+
+        assert_eq!(
+            nonzero_resources(entries.into_iter()).collect::<Vec<_>>(),
+            vec![(ResourceType::Energy, 150), (ResourceType::Utrium, 25)]
+        );
 
-    pub fn get_free_capacity(&self, ty: Option<ResourceType>) -> i32 {
-        self.get_free_capacity_internal(ty).unwrap_or(0)
+        assert_eq!(nonzero_resources(Vec::new().into_iter()).count(), 0);
     }
 
-    pub fn get_used_capacity(&self, ty: Option<ResourceType>) -> u32 {
-        self.get_used_capacity_internal(ty).unwrap_or(0)
+    #[test]
+    fn free_capacity_of_restricted_stores() {
+        // a container holding 500 energy, with a general capacity of 2000
+        assert_eq!(free_capacity(Some(2_000), Some(1_500)), Some(1_500));
+        // a creep without any carry parts
+        assert_eq!(free_capacity(Some(0), Some(0)), Some(0));
+
+        // a lab's general capacity is `null`, and `null - null` is 0
+        assert_eq!(free_capacity(None, Some(0)), None);
+        // a lab holding a different mineral than the one asked about
+        assert_eq!(free_capacity(None, None), None);
+        // a lab's energy capacity
+        assert_eq!(free_capacity(Some(2_000), Some(2_000)), Some(2_000));
+    }
+
+    #[test]
+    fn terminal_accepting_resources() {
+        let resources = HashMap::from([
+            (ResourceType::Energy, 20_000),
+            (ResourceType::Hydrogen, 5_000),
+        ]);
+        // terminals have a general capacity, shared by every resource
+        let unused = |_| unreachable!();
+
+        assert!(accepts_all(&resources, Some(25_000), unused));
+        assert!(accepts_all(&resources, Some(300_000), unused));
+        assert!(!accepts_all(&resources, Some(24_999), unused));
+        assert!(accepts_all(&HashMap::new(), Some(0), unused));
+    }
+
+    #[test]
+    fn restricted_store_accepting_resources() {
+        // a lab holding 1000 hydrogen, with its energy partly filled
+        let lab_free = |ty| match ty {
+            ResourceType::Energy => Some(500),
+            ResourceType::Hydrogen => Some(2_000),
+            _ => None,
+        };
+
+        let resources =
+            HashMap::from([(ResourceType::Energy, 500), (ResourceType::Hydrogen, 2_000)]);
+        assert!(accepts_all(&resources, None, lab_free));
+
+        let resources = HashMap::from([(ResourceType::Energy, 501)]);
+        assert!(!accepts_all(&resources, None, lab_free));
+
+        // a lab can't take a second mineral
+        let resources = HashMap::from([(ResourceType::Oxygen, 1)]);
+        assert!(!accepts_all(&resources, None, lab_free));
+        let resources = HashMap::from([(ResourceType::Oxygen, 0)]);
+        assert!(accepts_all(&resources, None, lab_free));
+    }
+
+    #[test]
+    fn empty_lab_accepting_one_mineral() {
+        // an empty lab has room for any one mineral, alongside its energy
+        let empty_lab_free = |ty| match ty {
+            ResourceType::Energy => Some(2_000),
+            _ => Some(3_000),
+        };
+
+        let resources = HashMap::from([
+            (ResourceType::Energy, 2_000),
+            (ResourceType::Hydrogen, 3_000),
+        ]);
+        assert!(accepts_all(&resources, None, empty_lab_free));
+
+        // but not two different minerals at once
+        let resources = HashMap::from([(ResourceType::Hydrogen, 100), (ResourceType::Oxygen, 100)]);
+        assert!(!accepts_all(&resources, None, empty_lab_free));
+        let resources = HashMap::from([
+            (ResourceType::Energy, 100),
+            (ResourceType::Hydrogen, 100),
+            (ResourceType::Oxygen, 0),
+        ]);
+        assert!(accepts_all(&resources, None, empty_lab_free));
     }
 }
@@ -113,8 +113,13 @@ where
     fn is_active(&self) -> bool {
         Structure::is_active(self.as_ref())
     }
+}
 
-    fn notify_when_attacked(&self, val: bool) -> Result<(), ErrorCode> {
-        ErrorCode::result_from_i8(Structure::notify_when_attacked(self.as_ref(), val))
+impl<T> NotifyWhenAttacked for T
+where
+    T: AsRef<Structure>,
+{
+    fn notify_when_attacked(&self, enabled: bool) -> Result<(), ErrorCode> {
+        ErrorCode::result_from_i8(Structure::notify_when_attacked(self.as_ref(), enabled))
     }
 }
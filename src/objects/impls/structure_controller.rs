@@ -1,4 +1,5 @@
 use js_sys::Date;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 use crate::{
@@ -109,8 +110,18 @@ impl StructureController {
     /// Activate safe mode for the room, preventing hostile creep actions in the
     /// room for 20,000 ticks
     ///
+    /// Returns [`ErrorCode::NotEnough`] without calling into the game if there
+    /// are no [`StructureController::safe_mode_available`] activations, or
+    /// [`ErrorCode::Tired`] if safe mode is on cooldown or blocked by
+    /// [`StructureController::upgrade_blocked`].
+    ///
     /// [Screeps documentation](https://docs.screeps.com/api/#StructureController.activateSafeMode)
     pub fn activate_safe_mode(&self) -> Result<(), ErrorCode> {
+        check_safe_mode_activation(
+            self.safe_mode_available(),
+            self.safe_mode_cooldown(),
+            self.upgrade_blocked(),
+        )?;
         ErrorCode::result_from_i8(self.activate_safe_mode_internal())
     }
 
@@ -120,6 +131,94 @@ impl StructureController {
     pub fn unclaim(&self) -> Result<(), ErrorCode> {
         ErrorCode::result_from_i8(self.unclaim_internal())
     }
+
+    /// The game tick on which the controller will downgrade if it isn't
+    /// upgraded before then, given the current [`game::time`], or `None` if
+    /// the controller is unowned.
+    ///
+    /// [`game::time`]: crate::game::time
+    pub fn downgrade_at_tick(&self, current_time: u32) -> Option<u32> {
+        downgrade_tick(self.ticks_to_downgrade(), current_time)
+    }
+}
+
+/// A change in a controller's level reported by [`ControllerTracker::update`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ControllerLevelChange {
+    /// The controller was upgraded to a higher level.
+    LevelUp { from: u8, to: u8 },
+    /// The controller lost levels, by downgrading or by being unclaimed, which
+    /// leaves it at level 0.
+    Downgrade { from: u8, to: u8 },
+}
+
+/// Tracks a controller's level and progress across ticks to report when it
+/// levels up or downgrades.
+///
+/// The tracker can be serialized and kept in the room's memory between ticks,
+/// calling [`ControllerTracker::update`] once per tick with the current
+/// controller.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ControllerTracker {
+    level: Option<u8>,
+    progress: Option<u32>,
+}
+
+impl ControllerTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The controller level seen by the last update, or `None` before the
+    /// first update.
+    pub fn level(&self) -> Option<u8> {
+        self.level
+    }
+
+    /// The controller's [`StructureController::progress`] seen by the last
+    /// update, or `None` before the first update or if it was unowned.
+    pub fn progress(&self) -> Option<u32> {
+        self.progress
+    }
+
+    /// Record the controller's current level and progress, returning how its
+    /// level changed since the last update.
+    ///
+    /// The first update only records the controller, so never reports a
+    /// change.
+    pub fn update(&mut self, controller: &StructureController) -> Option<ControllerLevelChange> {
+        self.observe(controller.level(), controller.progress())
+    }
+
+    fn observe(&mut self, level: u8, progress: Option<u32>) -> Option<ControllerLevelChange> {
+        let previous = self.level.replace(level);
+        self.progress = progress;
+        match previous {
+            Some(from) if level > from => Some(ControllerLevelChange::LevelUp { from, to: level }),
+            Some(from) if level < from => {
+                Some(ControllerLevelChange::Downgrade { from, to: level })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn check_safe_mode_activation(
+    available: u32,
+    cooldown: Option<u32>,
+    upgrade_blocked: Option<u32>,
+) -> Result<(), ErrorCode> {
+    if available == 0 {
+        Err(ErrorCode::NotEnough)
+    } else if cooldown.unwrap_or(0) > 0 || upgrade_blocked.unwrap_or(0) > 0 {
+        Err(ErrorCode::Tired)
+    } else {
+        Ok(())
+    }
+}
+
+fn downgrade_tick(ticks_to_downgrade: Option<u32>, current_time: u32) -> Option<u32> {
+    ticks_to_downgrade.and_then(|ticks| current_time.checked_add(ticks))
 }
 
 #[wasm_bindgen]
@@ -163,3 +262,84 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn datetime(this: &Sign) -> Date;
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        check_safe_mode_activation, downgrade_tick, ControllerLevelChange, ControllerTracker,
+    };
+    use crate::constants::ErrorCode;
+
+    #[test]
+    fn downgrade_tick_from_game_time() {
+        // an RCL 4 controller with a freshly reset downgrade timer
+        assert_eq!(downgrade_tick(Some(40_000), 1_234_567), Some(1_274_567));
+        assert_eq!(downgrade_tick(Some(0), 1_234_567), Some(1_234_567));
+
+        // unowned controllers have no downgrade timer
+        assert_eq!(downgrade_tick(None, 1_234_567), None);
+
+        assert_eq!(downgrade_tick(Some(1), u32::MAX), None);
+    }
+
+    #[test]
+    fn safe_mode_available() {
+        assert_eq!(check_safe_mode_activation(1, None, None), Ok(()));
+        assert_eq!(check_safe_mode_activation(3, Some(0), Some(0)), Ok(()));
+    }
+
+    #[test]
+    fn safe_mode_unavailable() {
+        assert_eq!(
+            check_safe_mode_activation(0, None, None),
+            Err(ErrorCode::NotEnough)
+        );
+        // recently activated safe mode, with none left to use once it ends
+        assert_eq!(
+            check_safe_mode_activation(0, Some(49_000), None),
+            Err(ErrorCode::NotEnough)
+        );
+
+        assert_eq!(
+            check_safe_mode_activation(2, Some(49_000), None),
+            Err(ErrorCode::Tired)
+        );
+        // blocked by a creep attacking the controller
+        assert_eq!(
+            check_safe_mode_activation(2, None, Some(500)),
+            Err(ErrorCode::Tired)
+        );
+    }
+
+    #[test]
+    fn tracker_reports_level_up() {
+        let mut tracker = ControllerTracker::new();
+        assert_eq!(tracker.observe(2, Some(44_000)), None);
+        assert_eq!(tracker.observe(2, Some(44_900)), None);
+        assert_eq!(tracker.level(), Some(2));
+        assert_eq!(tracker.progress(), Some(44_900));
+
+        // progress resets after reaching the 45,000 needed for RCL 3
+        assert_eq!(
+            tracker.observe(3, Some(300)),
+            Some(ControllerLevelChange::LevelUp { from: 2, to: 3 })
+        );
+        assert_eq!(tracker.observe(3, Some(600)), None);
+
+        // keeping the tracker in memory between ticks
+        let stored = serde_json::to_string(&tracker).unwrap();
+        let mut tracker: ControllerTracker = serde_json::from_str(&stored).unwrap();
+        assert_eq!(tracker.level(), Some(3));
+
+        assert_eq!(
+            tracker.observe(2, Some(0)),
+            Some(ControllerLevelChange::Downgrade { from: 3, to: 2 })
+        );
+        // unclaiming leaves the controller at level 0 without any progress
+        assert_eq!(
+            tracker.observe(0, None),
+            Some(ControllerLevelChange::Downgrade { from: 2, to: 0 })
+        );
+        assert_eq!(tracker.progress(), None);
+    }
+}
@@ -1,7 +1,7 @@
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::{ErrorCode, ResourceType},
+    constants::{EffectType, ErrorCode, FactoryRecipe, PowerType, ResourceType},
     objects::{OwnedStructure, RoomObject, Store, Structure},
     prelude::*,
 };
@@ -49,6 +49,47 @@ impl StructureFactory {
     pub fn produce(&self, ty: ResourceType) -> Result<(), ErrorCode> {
         ErrorCode::result_from_i8(self.produce_internal(ty))
     }
+
+    /// Whether the factory is able to produce the given commodity, ignoring
+    /// its cooldown.
+    ///
+    /// The factory needs all of the recipe's components in its store. Recipes
+    /// with a level also need the factory to be that exact level and to be
+    /// operated by a power creep with [`PowerType::OperateFactory`].
+    ///
+    /// Returns `false` for resources without a factory recipe.
+    ///
+    /// [`PowerType::OperateFactory`]: crate::constants::PowerType::OperateFactory
+    pub fn can_produce(&self, resource: ResourceType) -> bool {
+        let Some(recipe) = resource.commodity_recipe() else {
+            return false;
+        };
+        let operated = self
+            .effects()
+            .iter()
+            .any(|effect| effect.effect() == EffectType::PowerEffect(PowerType::OperateFactory));
+        let store = self.store();
+        recipe_producible(&recipe, self.level(), operated, |component| {
+            store.get_used_capacity(Some(component)).unwrap_or(0)
+        })
+    }
+}
+
+fn recipe_producible(
+    recipe: &FactoryRecipe,
+    factory_level: u8,
+    operated: bool,
+    stored: impl Fn(ResourceType) -> u32,
+) -> bool {
+    let level_met = match recipe.level {
+        Some(level) => operated && u32::from(factory_level) == level,
+        None => true,
+    };
+    level_met
+        && recipe
+            .components
+            .iter()
+            .all(|(&component, &amount)| stored(component) >= amount)
 }
 
 impl HasCooldown for StructureFactory {
@@ -68,3 +109,48 @@ impl Dismantleable for StructureFactory {}
 impl Repairable for StructureFactory {}
 impl Transferable for StructureFactory {}
 impl Withdrawable for StructureFactory {}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::recipe_producible;
+    use crate::constants::ResourceType;
+
+    #[test]
+    fn producible_recipes() {
+        let store = HashMap::from([
+            (ResourceType::Energy, 1_000),
+            (ResourceType::Utrium, 600),
+            (ResourceType::UtriumBar, 100),
+            (ResourceType::Silicon, 100),
+        ]);
+        let stored = |resource| store.get(&resource).copied().unwrap_or(0);
+        let recipe = |resource: ResourceType| resource.commodity_recipe().unwrap();
+
+        // 500 utrium and 200 energy
+        let utrium_bar = recipe(ResourceType::UtriumBar);
+        assert!(recipe_producible(&utrium_bar, 0, false, stored));
+        assert!(recipe_producible(&utrium_bar, 3, true, stored));
+
+        // 20 utrium bars, 100 silicon, and 40 energy
+        let wire = recipe(ResourceType::Wire);
+        assert!(recipe_producible(&wire, 0, false, stored));
+        let store_without_silicon = |resource| match resource {
+            ResourceType::Silicon => 99,
+            _ => stored(resource),
+        };
+        assert!(!recipe_producible(&wire, 0, false, store_without_silicon));
+
+        // the factory is the right level, but has no wire or oxidant
+        let switch = recipe(ResourceType::Switch);
+        assert!(!recipe_producible(&switch, 1, true, stored));
+
+        // level 1 recipes need a level 1 factory which is operated
+        let stored = |_| 1_000;
+        assert!(recipe_producible(&switch, 1, true, stored));
+        assert!(!recipe_producible(&switch, 1, false, stored));
+        assert!(!recipe_producible(&switch, 2, true, stored));
+        assert!(!recipe_producible(&switch, 0, false, stored));
+    }
+}
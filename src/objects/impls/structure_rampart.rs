@@ -2,6 +2,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     constants::ErrorCode,
+    local::RoomXY,
     objects::{OwnedStructure, RoomObject, Structure},
     prelude::*,
 };
@@ -47,6 +48,19 @@ impl StructureRampart {
     }
 }
 
+/// Whether a rampart should be public, given the positions of allied creeps
+/// in its room, so that allies next to it can walk through it.
+///
+/// A public rampart lets every player's creeps through, not just allies, so
+/// it's only public while an ally is on or adjacent to it. Pass the result to
+/// [`StructureRampart::set_public`] each tick to open and close it as allies
+/// come and go.
+pub fn should_be_public(rampart_pos: RoomXY, allies_nearby: &[RoomXY]) -> bool {
+    allies_nearby
+        .iter()
+        .any(|&ally| ally.is_adjacent_to(rampart_pos))
+}
+
 impl CanDecay for StructureRampart {
     fn ticks_to_decay(&self) -> u32 {
         Self::ticks_to_decay(self)
@@ -56,3 +70,26 @@ impl CanDecay for StructureRampart {
 impl Attackable for StructureRampart {}
 impl Dismantleable for StructureRampart {}
 impl Repairable for StructureRampart {}
+
+#[cfg(test)]
+mod test {
+    use super::should_be_public;
+    use crate::local::RoomXY;
+
+    fn xy(x: u8, y: u8) -> RoomXY {
+        RoomXY::try_from((x, y)).unwrap()
+    }
+
+    #[test]
+    fn public_for_adjacent_ally() {
+        let rampart = xy(20, 20);
+
+        assert!(should_be_public(rampart, &[xy(21, 19)]));
+        assert!(should_be_public(rampart, &[xy(30, 30), xy(19, 20)]));
+        // an ally standing in the rampart must be able to leave it
+        assert!(should_be_public(rampart, &[xy(20, 20)]));
+
+        assert!(!should_be_public(rampart, &[xy(22, 20), xy(20, 18)]));
+        assert!(!should_be_public(rampart, &[]));
+    }
+}
@@ -1,8 +1,11 @@
+use std::{cmp::Reverse, collections::HashMap};
+
 use js_sys::{Array, JsString, Object};
 use wasm_bindgen::{prelude::*, JsCast};
 
 use crate::{
-    constants::{Direction, ErrorCode, Part},
+    constants::{Direction, ErrorCode, Part, CREEP_SPAWN_TIME},
+    local::RoomName,
     objects::{Creep, OwnedStructure, RoomObject, Store, Structure},
     prelude::*,
 };
@@ -143,6 +146,19 @@ impl StructureSpawn {
     pub fn renew_creep(&self, creep: &Creep) -> Result<(), ErrorCode> {
         ErrorCode::result_from_i8(self.renew_creep_internal(creep))
     }
+
+    /// Whether the spawn is free to start spawning a creep: it isn't already
+    /// [`StructureSpawn::spawning`], and its room has at least enough energy
+    /// available for a single-part creep.
+    pub fn is_available(&self) -> bool {
+        let energy_available = self.room().map_or(0, |room| room.energy_available());
+        spawn_available(self.spawning().is_some(), energy_available)
+    }
+}
+
+fn spawn_available(spawning: bool, energy_available: u32) -> bool {
+    // `MOVE` is tied with `CARRY` as the cheapest body part
+    !spawning && energy_available >= Part::Move.cost()
 }
 
 impl JsCollectionFromValue for StructureSpawn {
@@ -214,6 +230,122 @@ impl SpawnOptions {
     }
 }
 
+/// A creep waiting to be spawned, to be paired with a spawn by
+/// [`assign_spawns`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpawnRequest {
+    /// The body of the creep to spawn.
+    pub body: Vec<Part>,
+    /// The priority of the request, with higher priorities assigned first.
+    pub priority: u32,
+}
+
+impl SpawnRequest {
+    pub fn new(body: Vec<Part>, priority: u32) -> Self {
+        SpawnRequest { body, priority }
+    }
+
+    /// The energy cost of spawning the creep.
+    pub fn cost(&self) -> u32 {
+        self.body.iter().map(|part| part.cost()).sum()
+    }
+}
+
+/// Pairs spawn requests with idle spawns, returning the index of each request
+/// that should be spawned this tick with the index of the spawn to use.
+///
+/// Requests are handled from highest to lowest priority, with ties going in
+/// the order they're given. Each takes the first idle spawn whose room still
+/// has enough energy available after the requests already assigned to that
+/// room. Spawns which are already spawning are never assigned.
+///
+/// Assignment stops at the first request which can't be afforded by any idle
+/// spawn, so that cheaper low priority creeps don't use up the energy a
+/// higher priority creep is waiting on.
+pub fn assign_spawns(requests: &[SpawnRequest], spawns: &[StructureSpawn]) -> Vec<(usize, usize)> {
+    let spawns: Vec<_> = spawns
+        .iter()
+        .map(|spawn| {
+            if spawn.spawning().is_some() {
+                return None;
+            }
+            spawn
+                .room()
+                .map(|room| (room.name(), room.energy_available()))
+        })
+        .collect();
+    assign_by_priority(requests, &spawns)
+}
+
+/// Assignment for [`assign_spawns`], where each idle spawn is represented by
+/// its room's name and available energy, and busy spawns by `None`.
+fn assign_by_priority(
+    requests: &[SpawnRequest],
+    spawns: &[Option<(RoomName, u32)>],
+) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..requests.len()).collect();
+    order.sort_by_key(|&idx| Reverse(requests[idx].priority));
+
+    let mut room_energy: HashMap<RoomName, u32> = spawns.iter().flatten().copied().collect();
+    let mut idle: Vec<Option<RoomName>> = spawns
+        .iter()
+        .map(|spawn| spawn.map(|(room, _)| room))
+        .collect();
+
+    let mut assignments = Vec::new();
+    for request_idx in order {
+        let cost = requests[request_idx].cost();
+        let spawn = idle.iter().enumerate().find_map(|(spawn_idx, room)| {
+            room.filter(|room| room_energy[room] >= cost)
+                .map(|room| (spawn_idx, room))
+        });
+        let Some((spawn_idx, room)) = spawn else {
+            break;
+        };
+
+        idle[spawn_idx] = None;
+        if let Some(energy) = room_energy.get_mut(&room) {
+            *energy -= cost;
+        }
+        assignments.push((request_idx, spawn_idx));
+    }
+
+    assignments
+}
+
+/// Picks the best body that a room can afford from tiers of bodies, each given
+/// with its energy cost.
+///
+/// The best tier is the most expensive one with a cost no more than the room's
+/// [`Room::energy_capacity_available`], with ties going to the first tier
+/// given. Returns `None` if no tier is affordable.
+///
+/// [`Room::energy_capacity_available`]: crate::objects::Room::energy_capacity_available
+pub fn select_body_tier(capacity: u32, tiers: &[(u32, Vec<Part>)]) -> Option<&Vec<Part>> {
+    tiers
+        .iter()
+        .filter(|(cost, _)| *cost <= capacity)
+        .rev()
+        .max_by_key(|(cost, _)| *cost)
+        .map(|(_, body)| body)
+}
+
+/// The number of ticks until a spawn finishes spawning a creep with a body of
+/// `body_len` parts, after it's spent `progress` ticks on it.
+///
+/// Each part takes [`CREEP_SPAWN_TIME`] ticks, without any speedup from
+/// [`PowerType::OperateSpawn`]. For a creep that's already spawning,
+/// [`Spawning::remaining_time`] gives the remaining time directly, including
+/// any power effects.
+///
+/// [`PowerType::OperateSpawn`]: crate::constants::PowerType::OperateSpawn
+pub fn spawn_time_remaining(body_len: usize, progress: u32) -> u32 {
+    let need_time = u32::try_from(body_len)
+        .unwrap_or(u32::MAX)
+        .saturating_mul(CREEP_SPAWN_TIME);
+    need_time.saturating_sub(progress)
+}
+
 #[wasm_bindgen]
 extern "C" {
     /// Object with info on what a [`StructureSpawn`] or
@@ -282,3 +414,111 @@ impl Spawning {
         ErrorCode::result_from_i8(self.set_directions_internal(directions))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        assign_by_priority, select_body_tier, spawn_available, spawn_time_remaining, SpawnRequest,
+    };
+    use crate::{constants::Part, local::RoomName};
+
+    #[test]
+    fn spawning_and_idle_spawns() {
+        // idle spawn in a room with energy
+        assert!(spawn_available(false, 300));
+        assert!(spawn_available(false, 50));
+
+        // idle spawn with too little energy for any body part
+        assert!(!spawn_available(false, 49));
+        assert!(!spawn_available(false, 0));
+
+        // spawning spawns are never available, regardless of energy
+        assert!(!spawn_available(true, 300));
+        assert!(!spawn_available(true, 0));
+    }
+
+    #[test]
+    fn assign_two_requests_to_two_spawns() {
+        let room: RoomName = "W1N1".parse().unwrap();
+        let harvester = SpawnRequest::new(vec![Part::Work, Part::Carry, Part::Move], 1);
+        let defender = SpawnRequest::new(vec![Part::Attack, Part::Move], 5);
+        let requests = [harvester, defender];
+        assert_eq!(requests[0].cost(), 200);
+        assert_eq!(requests[1].cost(), 130);
+
+        // the defender has priority, then the harvester takes the other spawn
+        let spawns = [Some((room, 400)), Some((room, 400))];
+        assert_eq!(assign_by_priority(&requests, &spawns), vec![(1, 0), (0, 1)]);
+
+        // the room's energy is shared between its spawns, so after the defender
+        // there isn't enough left for the harvester
+        let spawns = [Some((room, 300)), Some((room, 300))];
+        assert_eq!(assign_by_priority(&requests, &spawns), vec![(1, 0)]);
+
+        // busy spawns are skipped
+        let spawns = [None, Some((room, 400))];
+        assert_eq!(assign_by_priority(&requests, &spawns), vec![(1, 1)]);
+
+        // the harvester doesn't take the energy the defender is waiting on
+        let spawns = [Some((room, 200)), Some((room, 200))];
+        assert_eq!(assign_by_priority(&requests, &spawns), vec![(1, 0)]);
+        let spawns = [Some((room, 100)), Some((room, 100))];
+        assert_eq!(assign_by_priority(&requests, &spawns), vec![]);
+    }
+
+    #[test]
+    fn half_complete_spawn() {
+        // a 20 part creep takes 60 ticks to spawn
+        assert_eq!(spawn_time_remaining(20, 0), 60);
+        assert_eq!(spawn_time_remaining(20, 30), 30);
+        assert_eq!(spawn_time_remaining(20, 59), 1);
+        assert_eq!(spawn_time_remaining(20, 60), 0);
+        assert_eq!(spawn_time_remaining(20, 75), 0);
+        assert_eq!(spawn_time_remaining(50, 75), 75);
+    }
+
+    #[test]
+    fn body_tier_selection() {
+        let small = vec![Part::Work, Part::Carry, Part::Move];
+        let medium = vec![Part::Work, Part::Work, Part::Carry, Part::Move, Part::Move];
+        let large = vec![
+            Part::Work,
+            Part::Work,
+            Part::Work,
+            Part::Work,
+            Part::Carry,
+            Part::Carry,
+            Part::Move,
+            Part::Move,
+            Part::Move,
+        ];
+        let cost = |body: &[Part]| body.iter().map(|part| part.cost()).sum::<u32>();
+        let tiers = [
+            (cost(&small), small),
+            (cost(&medium), medium),
+            (cost(&large), large),
+        ];
+        assert_eq!(
+            tiers.iter().map(|(cost, _)| *cost).collect::<Vec<_>>(),
+            [200, 350, 650]
+        );
+
+        // a fresh RCL 1 room, and one with a few extensions
+        assert_eq!(select_body_tier(300, &tiers), Some(&tiers[0].1));
+        assert_eq!(select_body_tier(550, &tiers), Some(&tiers[1].1));
+        assert_eq!(select_body_tier(350, &tiers), Some(&tiers[1].1));
+        assert_eq!(select_body_tier(649, &tiers), Some(&tiers[1].1));
+        assert_eq!(select_body_tier(650, &tiers), Some(&tiers[2].1));
+        assert_eq!(select_body_tier(12_900, &tiers), Some(&tiers[2].1));
+        assert_eq!(select_body_tier(199, &tiers), None);
+        assert_eq!(select_body_tier(300, &[]), None);
+
+        // tiers don't need to be sorted, and ties go to the first
+        let unsorted = [
+            (400, vec![Part::Work]),
+            (200, vec![Part::Carry]),
+            (400, vec![Part::Move]),
+        ];
+        assert_eq!(select_body_tier(500, &unsorted), Some(&vec![Part::Work]));
+    }
+}
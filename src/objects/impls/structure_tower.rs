@@ -1,7 +1,7 @@
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    constants::ErrorCode,
+    constants::{ErrorCode, TOWER_ENERGY_COST},
     objects::{OwnedStructure, RoomObject, Store, Structure},
     prelude::*,
 };
@@ -74,6 +74,24 @@ impl StructureTower {
     }
 }
 
+/// Estimates how many consecutive ticks a tower can keep acting, firing once
+/// per tick at [`TOWER_ENERGY_COST`] each, starting with `energy` and gaining
+/// `refill_per_tick` energy from haulers after each shot.
+///
+/// Returns [`u32::MAX`] when the refill covers the cost of every shot, since
+/// the tower never runs dry.
+pub fn tower_sustained_shots(energy: u32, refill_per_tick: u32) -> u32 {
+    if energy < TOWER_ENERGY_COST {
+        return 0;
+    }
+    if refill_per_tick >= TOWER_ENERGY_COST {
+        return u32::MAX;
+    }
+
+    // each shot after the first drains the net cost from what's left
+    (energy - TOWER_ENERGY_COST) / (TOWER_ENERGY_COST - refill_per_tick) + 1
+}
+
 impl HasStore for StructureTower {
     fn store(&self) -> Store {
         Self::store(self)
@@ -85,3 +103,22 @@ impl Dismantleable for StructureTower {}
 impl Repairable for StructureTower {}
 impl Transferable for StructureTower {}
 impl Withdrawable for StructureTower {}
+
+#[cfg(test)]
+mod test {
+    use super::tower_sustained_shots;
+    use crate::constants::TOWER_CAPACITY;
+
+    #[test]
+    fn sustained_shots() {
+        // a full tower with no refill fires until it's empty
+        assert_eq!(tower_sustained_shots(TOWER_CAPACITY, 0), 100);
+        assert_eq!(tower_sustained_shots(9, 0), 0);
+        assert_eq!(tower_sustained_shots(19, 0), 1);
+
+        // refilling 5 per tick halves the net cost of each shot after the first
+        assert_eq!(tower_sustained_shots(TOWER_CAPACITY, 5), 199);
+        assert_eq!(tower_sustained_shots(TOWER_CAPACITY, 10), u32::MAX);
+        assert_eq!(tower_sustained_shots(0, 20), 0);
+    }
+}
@@ -2,6 +2,7 @@ use js_sys::JsString;
 use wasm_bindgen::prelude::*;
 
 use crate::{
+    constants::ResourceType,
     objects::{RoomObject, Store},
     prelude::*,
 };
@@ -63,6 +64,16 @@ impl HasId for Tombstone {
     }
 }
 
+impl Lootable for Tombstone {
+    fn contents(&self) -> Vec<(ResourceType, u32)> {
+        Self::store(self).iter().collect()
+    }
+
+    fn ticks_to_decay(&self) -> u32 {
+        Self::ticks_to_decay(self)
+    }
+}
+
 impl HasStore for Tombstone {
     fn store(&self) -> Store {
         Self::store(self)
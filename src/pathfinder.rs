@@ -115,14 +115,24 @@ extern "C" {
     fn path_internal(this: &SearchResults) -> Array;
 
     /// The number of operations the pathfinding operation performed.
+    ///
+    /// An incomplete search with `ops` equal to its [`SearchOptions::max_ops`]
+    /// ran out of operations before it could find a path. One that used fewer
+    /// was stopped by another limit, such as [`SearchOptions::max_cost`], or
+    /// searched every reachable tile without finding the goal.
     #[wasm_bindgen(method, getter)]
     pub fn ops(this: &SearchResults) -> u32;
 
-    /// Total cost of all tiles used in the path
+    /// Total cost of all tiles used in the path, which may be incomplete.
     #[wasm_bindgen(method, getter)]
     pub fn cost(this: &SearchResults) -> u32;
 
-    /// Whether this search successfully found a complete path.
+    /// Whether the search failed to find a complete path to the goal, in
+    /// which case [`SearchResults::path`] leads to the closest tile reached.
+    ///
+    /// The engine doesn't report the number of rooms a search explored; the
+    /// room callback is called once for each room the search opens, so count
+    /// calls to it if that number is needed.
     #[wasm_bindgen(method, getter)]
     pub fn incomplete(this: &SearchResults) -> bool;
 }
@@ -269,6 +279,28 @@ where
         }
     }
 
+    /// Limits the search to a single room, treating every other room as
+    /// impassable.
+    pub(crate) fn within_room(
+        self,
+        room: RoomName,
+    ) -> SearchOptions<impl FnMut(RoomName) -> MultiRoomCostResult> {
+        let mut callback = self.callback;
+        SearchOptions {
+            callback: move |name| {
+                if name == room {
+                    callback(name)
+                } else {
+                    MultiRoomCostResult::Impassable
+                }
+            },
+            inner: InnerSearchOptions {
+                max_rooms: Some(1),
+                ..self.inner
+            },
+        }
+    }
+
     /// Sets plain cost - default `1`.
     #[inline]
     pub fn plain_cost(mut self, cost: u8) -> Self {
@@ -389,3 +421,26 @@ where
         PathFinder::search_internal(&from, goal, &JsValue::UNDEFINED)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{MultiRoomCostResult, SearchOptions};
+    use crate::local::RoomName;
+
+    #[test]
+    fn search_within_room() {
+        let w1n1: RoomName = "W1N1".parse().unwrap();
+        let w2n1: RoomName = "W2N1".parse().unwrap();
+
+        let mut opts = SearchOptions::default().max_rooms(8).within_room(w1n1);
+        assert_eq!(opts.inner.max_rooms, Some(1));
+        assert!(matches!(
+            (opts.callback)(w1n1),
+            MultiRoomCostResult::Default
+        ));
+        assert!(matches!(
+            (opts.callback)(w2n1),
+            MultiRoomCostResult::Impassable
+        ));
+    }
+}
@@ -136,6 +136,58 @@ pub trait HasPosition {
     fn pos(&self) -> Position;
 }
 
+/// Finds the candidate closest to `origin` by range.
+///
+/// Candidates in the same room as `origin` are always preferred over those in
+/// other rooms, since reaching another room means leaving this one. Within
+/// each group, candidates are compared by [`Position::get_range_to`], which
+/// measures range in world coordinates for positions in different rooms. Of
+/// equally close candidates, the first is returned.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{traits::find_closest_by_range, Position};
+///
+/// let origin = Position::from_world_coords(10, 10);
+/// let candidates = [
+///     Position::from_world_coords(15, 15),
+///     Position::from_world_coords(12, 11),
+///     Position::from_world_coords(8, 16),
+/// ];
+///
+/// assert_eq!(
+///     find_closest_by_range(origin, candidates.into_iter()),
+///     Some(Position::from_world_coords(12, 11))
+/// );
+/// ```
+pub fn find_closest_by_range<T>(origin: Position, candidates: impl Iterator<Item = T>) -> Option<T>
+where
+    T: HasPosition,
+{
+    candidates.min_by_key(|candidate| range_sort_key(origin, candidate.pos()))
+}
+
+/// Collects candidates into a [`Vec`] sorted by range from `origin`, closest
+/// first, using the same ordering as [`find_closest_by_range`].
+///
+/// The sort is stable, so equally close candidates keep their order.
+pub fn sort_by_range<T>(origin: Position, candidates: impl Iterator<Item = T>) -> Vec<T>
+where
+    T: HasPosition,
+{
+    let mut candidates: Vec<T> = candidates.collect();
+    candidates.sort_by_cached_key(|candidate| range_sort_key(origin, candidate.pos()));
+    candidates
+}
+
+fn range_sort_key(origin: Position, target: Position) -> (bool, u32) {
+    (
+        origin.room_name() != target.room_name(),
+        origin.get_range_to(target),
+    )
+}
+
 #[enum_dispatch]
 pub trait MaybeHasPosition {
     /// Position of the object, or `None` if the object is a power creep not
@@ -158,6 +210,20 @@ pub trait HasStore {
     fn store(&self) -> Store;
 }
 
+/// Trait for objects holding resources that can be picked up or withdrawn
+/// until they decay: dropped resources, tombstones, and ruins.
+pub trait Lootable: HasPosition {
+    /// The resources held by the object and the amount of each.
+    fn contents(&self) -> Vec<(ResourceType, u32)>;
+
+    /// The number of ticks until the object disappears.
+    ///
+    /// Dropped resources lose part of their amount every tick rather than
+    /// decaying all at once, so for them this is the number of ticks until
+    /// the whole amount is gone.
+    fn ticks_to_decay(&self) -> u32;
+}
+
 #[enum_dispatch]
 pub trait OwnedStructureProperties {
     /// Whether this structure is owned by the player.
@@ -218,8 +284,17 @@ pub trait SharedCreepProperties {
     /// The [`Owner`] of this creep that contains the owner's username.
     fn owner(&self) -> Owner;
 
-    /// What the creep said last tick.
-    fn saying(&self) -> Option<JsString>;
+    /// What the creep said last tick as a [`String`], or `None` if it didn't
+    /// say anything.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.saying)
+    fn saying(&self) -> Option<String>;
+
+    /// What the creep said last tick as a [`JsString`], or `None` if it didn't
+    /// say anything.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.saying)
+    fn saying_jsstring(&self) -> Option<JsString>;
 
     /// The number of ticks the creep has left to live.
     fn ticks_to_live(&self) -> Option<u32>;
@@ -262,9 +337,6 @@ pub trait SharedCreepProperties {
         T: HasPosition,
         F: FnMut(RoomName, CostMatrix) -> SingleRoomCostResult;
 
-    /// Whether to send an email notification when this creep is attacked.
-    fn notify_when_attacked(&self, enabled: bool) -> Result<(), ErrorCode>;
-
     /// Pick up a [`Resource`] in melee range (or at the same position as the
     /// creep).
     fn pickup(&self, target: &Resource) -> Result<(), ErrorCode>;
@@ -296,6 +368,30 @@ pub trait SharedCreepProperties {
     ) -> Result<(), ErrorCode>
     where
         T: Withdrawable + ?Sized;
+
+    /// Drop the resource and amount given by a [`ResourceMove`] on the ground
+    /// from the creep's [`Store`].
+    fn drop_resource(&self, resource: ResourceMove) -> Result<(), ErrorCode> {
+        self.drop(resource.resource, resource.amount)
+    }
+
+    /// Transfer the resource and amount given by a [`ResourceMove`] from the
+    /// creep's store to [`Structure`], [`PowerCreep`], or another [`Creep`].
+    fn transfer_resource<T>(&self, target: &T, resource: ResourceMove) -> Result<(), ErrorCode>
+    where
+        T: Transferable + ?Sized,
+    {
+        self.transfer(target, resource.resource, resource.amount)
+    }
+
+    /// Withdraw the resource and amount given by a [`ResourceMove`] from a
+    /// [`Structure`], [`Tombstone`], or [`Ruin`].
+    fn withdraw_resource<T>(&self, target: &T, resource: ResourceMove) -> Result<(), ErrorCode>
+    where
+        T: Withdrawable + ?Sized,
+    {
+        self.withdraw(target, resource.resource, resource.amount)
+    }
 }
 
 #[enum_dispatch]
@@ -305,8 +401,16 @@ pub trait StructureProperties {
     fn destroy(&self) -> Result<(), ErrorCode>;
 
     fn is_active(&self) -> bool;
+}
 
-    fn notify_when_attacked(&self, val: bool) -> Result<(), ErrorCode>;
+/// Trait for objects which can send an email notification when attacked:
+/// creeps, power creeps, and structures.
+#[enum_dispatch]
+pub trait NotifyWhenAttacked {
+    /// Whether to send an email notification when this object is attacked.
+    ///
+    /// [Screeps documentation](https://docs.screeps.com/api/#Creep.notifyWhenAttacked)
+    fn notify_when_attacked(&self, enabled: bool) -> Result<(), ErrorCode>;
 }
 
 /// Trait for all wrappers over Screeps JavaScript objects which can be the
@@ -372,3 +476,163 @@ pub trait Repairable: HasHits + AsRef<Structure> {}
 /// The reference returned from `AsRef<RoomObject>::as_ref` must be a valid
 /// target for `Creep.heal`.
 pub trait Healable: AsRef<RoomObject> {}
+
+#[cfg(test)]
+mod test {
+    use super::{find_closest_by_range, sort_by_range, HasPosition, Lootable};
+    use crate::{
+        constants::ResourceType,
+        local::{Position, RoomCoordinate, RoomName},
+    };
+
+    /// A dropped resource pile, in the shape [`Resource`]'s impl reports it.
+    ///
+    /// [`Resource`]: crate::objects::Resource
+    struct Pile {
+        pos: Position,
+        resource_type: ResourceType,
+        amount: u32,
+        ticks_to_decay: u32,
+    }
+
+    impl HasPosition for Pile {
+        fn pos(&self) -> Position {
+            self.pos
+        }
+    }
+
+    impl Lootable for Pile {
+        fn contents(&self) -> Vec<(ResourceType, u32)> {
+            vec![(self.resource_type, self.amount)]
+        }
+
+        fn ticks_to_decay(&self) -> u32 {
+            self.ticks_to_decay
+        }
+    }
+
+    /// A tombstone or ruin, holding a store of contents.
+    struct Remains {
+        pos: Position,
+        store: Vec<(ResourceType, u32)>,
+        ticks_to_decay: u32,
+    }
+
+    impl HasPosition for Remains {
+        fn pos(&self) -> Position {
+            self.pos
+        }
+    }
+
+    impl Lootable for Remains {
+        fn contents(&self) -> Vec<(ResourceType, u32)> {
+            self.store.clone()
+        }
+
+        fn ticks_to_decay(&self) -> u32 {
+            self.ticks_to_decay
+        }
+    }
+
+    #[test]
+    fn lootables_in_one_list() {
+        let lootables: Vec<Box<dyn Lootable>> = vec![
+            // a dropped pile of 1000 energy, losing one a tick
+            Box::new(Pile {
+                pos: pos(10, 10, "W1N1"),
+                resource_type: ResourceType::Energy,
+                amount: 1_000,
+                ticks_to_decay: 1_000,
+            }),
+            // a creep's tombstone
+            Box::new(Remains {
+                pos: pos(20, 20, "W1N1"),
+                store: vec![(ResourceType::Energy, 50), (ResourceType::Hydrogen, 100)],
+                ticks_to_decay: 85,
+            }),
+            // an empty ruin
+            Box::new(Remains {
+                pos: pos(30, 30, "W1N1"),
+                store: Vec::new(),
+                ticks_to_decay: 400,
+            }),
+        ];
+
+        let energy: u32 = lootables
+            .iter()
+            .flat_map(|lootable| lootable.contents())
+            .filter(|&(ty, _)| ty == ResourceType::Energy)
+            .map(|(_, amount)| amount)
+            .sum();
+        assert_eq!(energy, 1_050);
+
+        let worth_looting: Vec<Position> = lootables
+            .iter()
+            .filter(|lootable| !lootable.contents().is_empty())
+            .map(|lootable| lootable.pos())
+            .collect();
+        assert_eq!(worth_looting, [pos(10, 10, "W1N1"), pos(20, 20, "W1N1")]);
+
+        let soonest = lootables
+            .iter()
+            .min_by_key(|lootable| lootable.ticks_to_decay())
+            .unwrap();
+        assert_eq!(soonest.pos(), pos(20, 20, "W1N1"));
+    }
+
+    fn pos(x: u8, y: u8, room: &str) -> Position {
+        Position::new(
+            RoomCoordinate::new(x).unwrap(),
+            RoomCoordinate::new(y).unwrap(),
+            RoomName::new(room).unwrap(),
+        )
+    }
+
+    #[test]
+    fn closest_in_same_room() {
+        let origin = pos(25, 25, "W1N1");
+        let candidates = [
+            pos(30, 30, "W1N1"),
+            pos(20, 27, "W1N1"),
+            pos(27, 23, "W1N1"),
+        ];
+
+        assert_eq!(
+            find_closest_by_range(origin, candidates.into_iter()),
+            Some(pos(27, 23, "W1N1"))
+        );
+        assert_eq!(
+            find_closest_by_range(origin, [].into_iter()),
+            None::<Position>
+        );
+    }
+
+    #[test]
+    fn same_room_preferred_over_other_rooms() {
+        // the tile just across the border is 1 away in world coordinates, but
+        // is still less preferred than anything in the origin's room
+        let origin = pos(0, 25, "W1N1");
+        let candidates = [
+            pos(49, 25, "W2N1"),
+            pos(40, 25, "W1N1"),
+            pos(2, 25, "W1N1"),
+            pos(25, 25, "W2N1"),
+            pos(0, 10, "W1N2"),
+        ];
+
+        assert_eq!(
+            find_closest_by_range(origin, candidates.into_iter()),
+            Some(pos(2, 25, "W1N1"))
+        );
+        assert_eq!(
+            sort_by_range(origin, candidates.into_iter()),
+            vec![
+                pos(2, 25, "W1N1"),
+                pos(40, 25, "W1N1"),
+                pos(49, 25, "W2N1"),
+                pos(25, 25, "W2N1"),
+                pos(0, 10, "W1N2"),
+            ]
+        );
+    }
+}